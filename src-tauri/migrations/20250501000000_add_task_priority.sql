@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE tasks ADD COLUMN priority INTEGER NOT NULL DEFAULT 4;
@@ -0,0 +1,15 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS labels (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    color TEXT,
+    created_at_utc DATETIME NOT NULL,
+    updated_at_utc DATETIME NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS task_labels (
+    task_id TEXT NOT NULL,
+    label_id TEXT NOT NULL,
+    created_at_utc DATETIME NOT NULL,
+    PRIMARY KEY (task_id, label_id)
+);
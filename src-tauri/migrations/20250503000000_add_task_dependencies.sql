@@ -0,0 +1,7 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS task_dependencies (
+    task_id TEXT NOT NULL,
+    depends_on_task_id TEXT NOT NULL,
+    created_at_utc DATETIME NOT NULL,
+    PRIMARY KEY (task_id, depends_on_task_id)
+);
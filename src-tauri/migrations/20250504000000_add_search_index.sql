@@ -0,0 +1,42 @@
+-- Add migration script here
+CREATE VIRTUAL TABLE IF NOT EXISTS search_index USING fts5(
+    entity_type UNINDEXED, -- 'task', 'project'
+    entity_id UNINDEXED,
+    title,
+    description,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO search_index (entity_type, entity_id, title, description)
+SELECT 'task', id, title, COALESCE(description, '') FROM tasks;
+
+INSERT INTO search_index (entity_type, entity_id, title, description)
+SELECT 'project', id, title, COALESCE(description, '') FROM projects;
+
+CREATE TRIGGER IF NOT EXISTS tasks_search_index_insert AFTER INSERT ON tasks BEGIN
+    INSERT INTO search_index (entity_type, entity_id, title, description)
+    VALUES ('task', new.id, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS tasks_search_index_update AFTER UPDATE OF title, description ON tasks BEGIN
+    UPDATE search_index SET title = new.title, description = COALESCE(new.description, '')
+    WHERE entity_type = 'task' AND entity_id = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS tasks_search_index_delete AFTER DELETE ON tasks BEGIN
+    DELETE FROM search_index WHERE entity_type = 'task' AND entity_id = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS projects_search_index_insert AFTER INSERT ON projects BEGIN
+    INSERT INTO search_index (entity_type, entity_id, title, description)
+    VALUES ('project', new.id, new.title, COALESCE(new.description, ''));
+END;
+
+CREATE TRIGGER IF NOT EXISTS projects_search_index_update AFTER UPDATE OF title, description ON projects BEGIN
+    UPDATE search_index SET title = new.title, description = COALESCE(new.description, '')
+    WHERE entity_type = 'project' AND entity_id = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS projects_search_index_delete AFTER DELETE ON projects BEGIN
+    DELETE FROM search_index WHERE entity_type = 'project' AND entity_id = old.id;
+END;
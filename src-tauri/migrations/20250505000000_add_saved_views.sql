@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS saved_views (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    icon TEXT,
+    filter TEXT NOT NULL, -- filter expression, e.g. 'due:<7d & !completed'
+    sort_order TEXT NOT NULL DEFAULT 'priority', -- 'priority', 'due_date', 'created_at', 'updated_at', 'title'
+    created_at_utc DATETIME NOT NULL,
+    updated_at_utc DATETIME NOT NULL
+);
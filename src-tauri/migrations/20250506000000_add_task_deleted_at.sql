@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE tasks ADD COLUMN deleted_at_utc DATETIME;
@@ -0,0 +1,8 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS undo_operations (
+    id TEXT PRIMARY KEY,
+    description TEXT NOT NULL,
+    changes TEXT NOT NULL, -- JSON list of before/after snapshots
+    created_at_utc DATETIME NOT NULL,
+    undone_at_utc DATETIME -- set while the operation sits on the redo stack
+);
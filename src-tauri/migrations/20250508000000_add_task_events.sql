@@ -0,0 +1,12 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS task_events (
+    id TEXT PRIMARY KEY,
+    entity_type TEXT NOT NULL, -- 'task', 'project'
+    entity_id TEXT NOT NULL,
+    field TEXT NOT NULL, -- 'created', 'title', 'due_at_utc', 'completed_at_utc', ...
+    old_value TEXT,
+    new_value TEXT,
+    created_at_utc DATETIME NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS task_events_entity ON task_events (entity_type, entity_id);
@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE tasks ADD COLUMN archived_at_utc DATETIME;
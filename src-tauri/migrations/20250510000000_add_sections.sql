@@ -0,0 +1,13 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS sections (
+    id TEXT PRIMARY KEY,
+    project_id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    created_at_utc DATETIME NOT NULL,
+    updated_at_utc DATETIME NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_sections_project ON sections (project_id, position);
+
+ALTER TABLE tasks ADD COLUMN section_id TEXT;
@@ -0,0 +1,17 @@
+-- Add migration script here
+ALTER TABLE tasks ADD COLUMN position TEXT NOT NULL DEFAULT '';
+
+-- Keep the current order of every list: ranks are zero padded so they sort as text
+UPDATE tasks SET position = (
+    SELECT printf('%06di', ranked.rank)
+    FROM (
+        SELECT id, ROW_NUMBER() OVER (
+            PARTITION BY project_id, parent_task_id
+            ORDER BY priority ASC, updated_at_utc DESC
+        ) AS rank
+        FROM tasks
+    ) AS ranked
+    WHERE ranked.id = tasks.id
+);
+
+CREATE INDEX IF NOT EXISTS idx_tasks_position ON tasks (project_id, parent_task_id, position);
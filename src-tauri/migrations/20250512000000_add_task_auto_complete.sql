@@ -0,0 +1,3 @@
+-- Add migration script here
+-- NULL follows the global setting, 0 or 1 overrides it for this task
+ALTER TABLE tasks ADD COLUMN auto_complete BOOLEAN;
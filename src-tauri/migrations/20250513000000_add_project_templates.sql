@@ -0,0 +1,12 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS project_templates (
+    id TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    emoji TEXT,
+    color TEXT,
+    description TEXT,
+    sections TEXT NOT NULL,
+    tasks TEXT NOT NULL,
+    created_at_utc DATETIME NOT NULL,
+    updated_at_utc DATETIME NOT NULL
+);
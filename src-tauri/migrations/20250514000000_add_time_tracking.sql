@@ -0,0 +1,16 @@
+-- Add migration script here
+ALTER TABLE tasks ADD COLUMN estimated_minutes INTEGER;
+
+CREATE TABLE IF NOT EXISTS time_entries (
+    id TEXT PRIMARY KEY,
+    task_id TEXT NOT NULL,
+    started_at_utc DATETIME NOT NULL,
+    ended_at_utc DATETIME,
+    created_at_utc DATETIME NOT NULL,
+    updated_at_utc DATETIME NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_time_entries_task ON time_entries (task_id, started_at_utc);
+
+-- Only one timer can be running at a time
+CREATE UNIQUE INDEX IF NOT EXISTS idx_time_entries_running ON time_entries ((ended_at_utc IS NULL)) WHERE ended_at_utc IS NULL;
@@ -0,0 +1,10 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS pomodoro_sessions (
+    id TEXT PRIMARY KEY,
+    task_id TEXT,
+    started_at_utc DATETIME NOT NULL,
+    ended_at_utc DATETIME NOT NULL,
+    created_at_utc DATETIME NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_pomodoro_sessions_task ON pomodoro_sessions (task_id, started_at_utc);
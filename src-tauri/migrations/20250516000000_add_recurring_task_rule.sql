@@ -0,0 +1,3 @@
+-- Add migration script here
+-- RFC 5545 RRULE, NULL when the frequency and interval describe the recurrence
+ALTER TABLE recurring_tasks ADD COLUMN rule TEXT;
@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE recurring_tasks ADD COLUMN anchor TEXT NOT NULL DEFAULT 'scheduled'; -- 'scheduled', 'completion'
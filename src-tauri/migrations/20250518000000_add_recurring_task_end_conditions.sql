@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE recurring_tasks ADD COLUMN until_utc TEXT;
+ALTER TABLE recurring_tasks ADD COLUMN remaining_occurrences INTEGER;
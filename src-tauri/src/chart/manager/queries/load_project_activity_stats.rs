@@ -21,7 +21,7 @@ impl ProjectActivityStats {
         let project_title = project_row.get::<String, _>("title");
 
         let completed_count = sqlx::query(
-            "SELECT COUNT(*) as count FROM tasks WHERE project_id = ?1 AND completed_at_utc IS NOT NULL AND completed_at_utc >= ?2 AND completed_at_utc <= ?3"
+            "SELECT COUNT(*) as count FROM tasks WHERE project_id = ?1 AND completed_at_utc IS NOT NULL AND archived_at_utc IS NULL AND completed_at_utc >= ?2 AND completed_at_utc <= ?3"
         )
         .bind(&project_id)
         .bind(since.to_rfc3339())
@@ -64,7 +64,7 @@ impl ChartManager<'_> {
             LEFT JOIN tasks t ON p.id = t.project_id 
             WHERE p.archived_at_utc IS NULL 
             AND (
-                (t.completed_at_utc IS NOT NULL AND t.completed_at_utc >= ?1 AND t.completed_at_utc <= ?2)
+                (t.completed_at_utc IS NOT NULL AND t.archived_at_utc IS NULL AND t.completed_at_utc >= ?1 AND t.completed_at_utc <= ?2)
                 OR (t.created_at_utc >= ?1 AND t.created_at_utc <= ?2)
             )",
         )
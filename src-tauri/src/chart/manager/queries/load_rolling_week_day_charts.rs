@@ -11,7 +11,7 @@ impl RollingWeekDayCharts {
         connection: &mut PoolConnection<Sqlite>,
     ) -> Result<i32, Box<dyn Error>> {
         let mut sqlx_result = sqlx::query(
-            "SELECT COUNT(*) AS completed_count, strftime('%Y-%m-%d', completed_at_utc) as date FROM tasks WHERE completed_at_utc LIKE ?1 GROUP BY date LIMIT 1"
+            "SELECT COUNT(*) AS completed_count, strftime('%Y-%m-%d', completed_at_utc) as date FROM tasks WHERE completed_at_utc LIKE ?1 AND archived_at_utc IS NULL GROUP BY date LIMIT 1"
         )
         .bind(format!("{}%", date.format("%Y-%m-%d")))
         .fetch_all(&mut **connection)
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row as SqlxRow;
+
+use crate::chart::manager::queries::{ProjectTimeStats, TaskTimeStats};
+use crate::chart::manager::ChartManager;
+
+// Seconds tracked by an entry, running timers counting up to ?3
+const TRACKED_SECONDS: &str = "SUM(CAST(strftime('%s', COALESCE(e.ended_at_utc, ?3)) AS INTEGER) - CAST(strftime('%s', e.started_at_utc) AS INTEGER))";
+
+impl ChartManager<'_> {
+    pub async fn load_task_time_stats(
+        &self,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<TaskTimeStats>, Box<dyn std::error::Error>> {
+        let mut connection = self.db_pool.acquire().await?;
+
+        let rows = sqlx::query(&format!(
+            "SELECT t.id, t.title, t.project_id, t.estimated_minutes, {} AS tracked_seconds
+            FROM time_entries e
+            JOIN tasks t ON t.id = e.task_id
+            WHERE t.deleted_at_utc IS NULL AND e.started_at_utc >= ?1 AND e.started_at_utc <= ?2
+            GROUP BY t.id
+            ORDER BY tracked_seconds DESC",
+            TRACKED_SECONDS
+        ))
+        .bind(since.to_rfc3339())
+        .bind(until.to_rfc3339())
+        .bind(Utc::now().to_rfc3339())
+        .fetch_all(&mut *connection)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TaskTimeStats {
+                task_id: row.get("id"),
+                task_title: row.get("title"),
+                project_id: row.get("project_id"),
+                estimated_minutes: row.get("estimated_minutes"),
+                tracked_seconds: row.get("tracked_seconds"),
+            })
+            .collect())
+    }
+
+    pub async fn load_project_time_stats(
+        &self,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<ProjectTimeStats>, Box<dyn std::error::Error>> {
+        let mut connection = self.db_pool.acquire().await?;
+
+        let rows = sqlx::query(&format!(
+            "SELECT p.id, p.title, {} AS tracked_seconds
+            FROM time_entries e
+            JOIN tasks t ON t.id = e.task_id
+            JOIN projects p ON p.id = t.project_id
+            WHERE t.deleted_at_utc IS NULL AND e.started_at_utc >= ?1 AND e.started_at_utc <= ?2
+            GROUP BY p.id
+            ORDER BY tracked_seconds DESC",
+            TRACKED_SECONDS
+        ))
+        .bind(since.to_rfc3339())
+        .bind(until.to_rfc3339())
+        .bind(Utc::now().to_rfc3339())
+        .fetch_all(&mut *connection)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ProjectTimeStats {
+                project_id: row.get("id"),
+                project_title: row.get("title"),
+                tracked_seconds: row.get("tracked_seconds"),
+            })
+            .collect())
+    }
+}
@@ -2,6 +2,7 @@ use serde::Serialize;
 
 mod load_project_activity_stats;
 mod load_rolling_week_day_charts;
+mod load_time_tracking_stats;
 
 #[derive(Debug, Serialize)]
 pub struct RollingWeekDayCharts {
@@ -17,3 +18,19 @@ pub struct ProjectActivityStats {
     pub completed_tasks: i32,
     pub created_tasks: i32,
 }
+
+#[derive(Debug, Serialize)]
+pub struct TaskTimeStats {
+    pub task_id: String,
+    pub task_title: String,
+    pub project_id: Option<String>,
+    pub estimated_minutes: Option<i64>,
+    pub tracked_seconds: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProjectTimeStats {
+    pub project_id: String,
+    pub project_title: String,
+    pub tracked_seconds: i64,
+}
@@ -41,3 +41,39 @@ pub async fn load_project_activity_stats_command(
 
     Ok(serde_json::to_string(&stats).unwrap())
 }
+
+#[tauri::command]
+pub async fn load_task_time_stats_command(
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    db_pool: State<'_, SqlitePool>,
+) -> Result<String, String> {
+    log::debug!("Running load task time stats command");
+
+    let manager = ChartManager::new(&db_pool);
+
+    let stats = manager
+        .load_task_time_stats(since, until)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&stats).unwrap())
+}
+
+#[tauri::command]
+pub async fn load_project_time_stats_command(
+    since: DateTime<Utc>,
+    until: DateTime<Utc>,
+    db_pool: State<'_, SqlitePool>,
+) -> Result<String, String> {
+    log::debug!("Running load project time stats command");
+
+    let manager = ChartManager::new(&db_pool);
+
+    let stats = manager
+        .load_project_time_stats(since, until)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&stats).unwrap())
+}
@@ -25,7 +25,8 @@ mod chart_manager_test {
         deadline_at_utc DATETIME,
         created_at_utc DATETIME NOT NULL,
         completed_at_utc DATETIME,
-        updated_at_utc DATETIME NOT NULL
+        updated_at_utc DATETIME NOT NULL,
+        archived_at_utc DATETIME
     )
         "#,
         )
@@ -1,5 +1,6 @@
-use crate::configuration::Configuration;
+use crate::configuration::{default_trash_retention_days, Configuration};
 use crate::ollama::OllamaConfig;
+use crate::pomodoro::PomodoroConfig;
 use std::fs::OpenOptions;
 use std::path::PathBuf;
 
@@ -52,6 +53,9 @@ impl ConfigurationManager {
                     config_path: storage_manager.configuration_path.clone(),
                     db_path: storage_manager.db_path.clone(),
                     ollama: OllamaConfig::default(),
+                    trash_retention_days: default_trash_retention_days(),
+                    auto_complete_parent_tasks: false,
+                    pomodoro: PomodoroConfig::default(),
                 };
 
                 let _ = storage_manager.write_to_file(
@@ -1,4 +1,5 @@
 use crate::ollama::OllamaConfig;
+use crate::pomodoro::PomodoroConfig;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -17,6 +18,20 @@ pub struct Configuration {
     pub db_path: PathBuf,
     #[serde(default)]
     pub ollama: OllamaConfig,
+    #[serde(
+        rename = "trashRetentionDays",
+        default = "default_trash_retention_days"
+    )]
+    pub trash_retention_days: u32,
+    // Completes a parent task once all its subtasks are done, unless the task says otherwise
+    #[serde(rename = "autoCompleteParentTasks", default)]
+    pub auto_complete_parent_tasks: bool,
+    #[serde(default)]
+    pub pomodoro: PomodoroConfig,
+}
+
+pub(crate) fn default_trash_retention_days() -> u32 {
+    30
 }
 
 impl Default for Configuration {
@@ -27,6 +42,9 @@ impl Default for Configuration {
             config_path: ".config.toml".into(),
             db_path: "file.db".into(),
             ollama: OllamaConfig::default(),
+            trash_retention_days: default_trash_retention_days(),
+            auto_complete_parent_tasks: false,
+            pomodoro: PomodoroConfig::default(),
         }
     }
 }
@@ -0,0 +1,40 @@
+use std::error::Error;
+use uuid::Uuid;
+
+use super::repository::HistoryRepository;
+use super::{HistoryEntityType, HistoryEvent};
+use crate::repository::RepositoryProvider;
+
+pub struct HistoryManager<'a> {
+    repository_provider: &'a RepositoryProvider,
+}
+
+impl<'a> HistoryManager<'a> {
+    pub fn new(repository_provider: &'a RepositoryProvider) -> Self {
+        Self {
+            repository_provider,
+        }
+    }
+
+    pub async fn load_task_history(
+        &self,
+        task_id: Uuid,
+    ) -> Result<Vec<HistoryEvent>, Box<dyn Error>> {
+        let mut repository = self.repository_provider.history_repository().await?;
+        repository
+            .find_by_entity(HistoryEntityType::Task, task_id)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn load_project_history(
+        &self,
+        project_id: Uuid,
+    ) -> Result<Vec<HistoryEvent>, Box<dyn Error>> {
+        let mut repository = self.repository_provider.history_repository().await?;
+        repository
+            .find_by_entity(HistoryEntityType::Project, project_id)
+            .await
+            .map_err(Into::into)
+    }
+}
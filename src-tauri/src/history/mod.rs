@@ -0,0 +1,280 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::project::Project;
+use crate::task::Task;
+
+pub mod manager;
+pub mod repository;
+pub mod tauri;
+mod test;
+
+#[derive(Debug, Serialize, Clone, PartialEq, strum_macros::Display, strum_macros::EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum HistoryEntityType {
+    Task,
+    Project,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, strum_macros::Display, strum_macros::EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum HistoryField {
+    Created,
+    Title,
+    Description,
+    ProjectId,
+    ParentTaskId,
+    DueAtUtc,
+    Priority,
+    CompletedAtUtc,
+    DeletedAtUtc,
+    Emoji,
+    Color,
+    ArchivedAtUtc,
+    IsFavorite,
+    SectionId,
+    AutoComplete,
+    EstimatedMinutes,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct HistoryEvent {
+    pub id: Uuid,
+    pub entity_type: HistoryEntityType,
+    pub entity_id: Uuid,
+    pub field: HistoryField,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub created_at_utc: DateTime<Utc>,
+}
+
+impl HistoryEvent {
+    pub fn new(
+        entity_type: HistoryEntityType,
+        entity_id: Uuid,
+        field: HistoryField,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            entity_type,
+            entity_id,
+            field,
+            old_value,
+            new_value,
+            created_at_utc: Utc::now(),
+        }
+    }
+}
+
+fn push_if_changed(
+    events: &mut Vec<HistoryEvent>,
+    entity_type: &HistoryEntityType,
+    entity_id: Uuid,
+    field: HistoryField,
+    old_value: Option<String>,
+    new_value: Option<String>,
+) {
+    if old_value != new_value {
+        events.push(HistoryEvent::new(
+            entity_type.clone(),
+            entity_id,
+            field,
+            old_value,
+            new_value,
+        ));
+    }
+}
+
+/// Lists the fields that differ between two versions of a task. A task
+/// without a previous version gets a single `created` event instead.
+pub fn task_changes(before: Option<&Task>, after: &Task) -> Vec<HistoryEvent> {
+    let entity_type = HistoryEntityType::Task;
+    let before = match before {
+        Some(before) => before,
+        None => {
+            return vec![HistoryEvent::new(
+                entity_type,
+                after.id,
+                HistoryField::Created,
+                None,
+                Some(after.title.clone()),
+            )]
+        }
+    };
+
+    let mut events = Vec::new();
+    let id = after.id;
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::Title,
+        Some(before.title.clone()),
+        Some(after.title.clone()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::Description,
+        before.description.clone(),
+        after.description.clone(),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::ProjectId,
+        before.project_id.map(|id| id.to_string()),
+        after.project_id.map(|id| id.to_string()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::ParentTaskId,
+        before.parent_task_id.map(|id| id.to_string()),
+        after.parent_task_id.map(|id| id.to_string()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::DueAtUtc,
+        before.due_at_utc.map(|date| date.to_rfc3339()),
+        after.due_at_utc.map(|date| date.to_rfc3339()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::Priority,
+        Some(before.priority.to_string()),
+        Some(after.priority.to_string()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::CompletedAtUtc,
+        before.completed_at_utc.map(|date| date.to_rfc3339()),
+        after.completed_at_utc.map(|date| date.to_rfc3339()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::DeletedAtUtc,
+        before.deleted_at_utc.map(|date| date.to_rfc3339()),
+        after.deleted_at_utc.map(|date| date.to_rfc3339()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::ArchivedAtUtc,
+        before.archived_at_utc.map(|date| date.to_rfc3339()),
+        after.archived_at_utc.map(|date| date.to_rfc3339()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::SectionId,
+        before.section_id.map(|id| id.to_string()),
+        after.section_id.map(|id| id.to_string()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::AutoComplete,
+        before.auto_complete.map(|enabled| enabled.to_string()),
+        after.auto_complete.map(|enabled| enabled.to_string()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::EstimatedMinutes,
+        before.estimated_minutes.map(|minutes| minutes.to_string()),
+        after.estimated_minutes.map(|minutes| minutes.to_string()),
+    );
+
+    events
+}
+
+/// Same as `task_changes`, for projects.
+pub fn project_changes(before: Option<&Project>, after: &Project) -> Vec<HistoryEvent> {
+    let entity_type = HistoryEntityType::Project;
+    let before = match before {
+        Some(before) => before,
+        None => {
+            return vec![HistoryEvent::new(
+                entity_type,
+                after.id,
+                HistoryField::Created,
+                None,
+                Some(after.title.clone()),
+            )]
+        }
+    };
+
+    let mut events = Vec::new();
+    let id = after.id;
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::Title,
+        Some(before.title.clone()),
+        Some(after.title.clone()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::Description,
+        before.description.clone(),
+        after.description.clone(),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::Emoji,
+        before.emoji.clone(),
+        after.emoji.clone(),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::Color,
+        before.color.clone(),
+        after.color.clone(),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::ArchivedAtUtc,
+        before.archived_at_utc.map(|date| date.to_rfc3339()),
+        after.archived_at_utc.map(|date| date.to_rfc3339()),
+    );
+    push_if_changed(
+        &mut events,
+        &entity_type,
+        id,
+        HistoryField::IsFavorite,
+        Some(before.is_favorite.to_string()),
+        Some(after.is_favorite.to_string()),
+    );
+
+    events
+}
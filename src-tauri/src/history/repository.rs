@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{pool::PoolConnection, Row, Sqlite, SqliteConnection};
+use uuid::Uuid;
+
+use super::{HistoryEntityType, HistoryEvent, HistoryField};
+
+#[async_trait]
+pub trait HistoryRepository: Send + Sync {
+    async fn find_by_entity(
+        &mut self,
+        entity_type: HistoryEntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<HistoryEvent>, sqlx::Error>;
+}
+
+pub struct SqliteHistoryRepository {
+    connection: PoolConnection<Sqlite>,
+}
+
+impl SqliteHistoryRepository {
+    pub fn new(connection: PoolConnection<Sqlite>) -> Self {
+        Self { connection }
+    }
+
+    fn row_to_event(row: sqlx::sqlite::SqliteRow) -> Result<HistoryEvent, sqlx::Error> {
+        let id = Uuid::parse_str(row.get("id")).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let entity_type = row
+            .get::<String, _>("entity_type")
+            .parse::<HistoryEntityType>()
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let entity_id =
+            Uuid::parse_str(row.get("entity_id")).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let field = row
+            .get::<String, _>("field")
+            .parse::<HistoryField>()
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let created_at_utc = DateTime::parse_from_rfc3339(row.get("created_at_utc"))
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?
+            .with_timezone(&Utc);
+
+        Ok(HistoryEvent {
+            id,
+            entity_type,
+            entity_id,
+            field,
+            old_value: row.get("old_value"),
+            new_value: row.get("new_value"),
+            created_at_utc,
+        })
+    }
+}
+
+/// Appends events using the caller's connection, so repositories writing
+/// tasks and projects can log their changes alongside the write itself.
+pub async fn insert_events(
+    connection: &mut SqliteConnection,
+    events: &[HistoryEvent],
+) -> Result<(), sqlx::Error> {
+    for event in events {
+        sqlx::query(
+            "INSERT INTO task_events (id, entity_type, entity_id, field, old_value, new_value, created_at_utc) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        )
+        .bind(event.id.to_string())
+        .bind(event.entity_type.to_string())
+        .bind(event.entity_id.to_string())
+        .bind(event.field.to_string())
+        .bind(&event.old_value)
+        .bind(&event.new_value)
+        .bind(event.created_at_utc.to_rfc3339())
+        .execute(&mut *connection)
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl HistoryRepository for SqliteHistoryRepository {
+    async fn find_by_entity(
+        &mut self,
+        entity_type: HistoryEntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<HistoryEvent>, sqlx::Error> {
+        let rows = sqlx::query(
+            "SELECT * FROM task_events WHERE entity_type = ?1 AND entity_id = ?2 ORDER BY created_at_utc ASC, id ASC",
+        )
+        .bind(entity_type.to_string())
+        .bind(entity_id.to_string())
+        .fetch_all(&mut *self.connection)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_event).collect()
+    }
+}
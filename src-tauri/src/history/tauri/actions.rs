@@ -0,0 +1 @@
+// History is written by the task and project repositories, there are no history actions
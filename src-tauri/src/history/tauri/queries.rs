@@ -0,0 +1,45 @@
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::history::manager::HistoryManager;
+use crate::repository::RepositoryProvider;
+
+#[tauri::command]
+pub async fn load_task_history_command(
+    task_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load task history command for task ID: {}", task_id);
+
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let history_manager = HistoryManager::new(&repository_provider);
+
+    let events = history_manager
+        .load_task_history(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&events).unwrap())
+}
+
+#[tauri::command]
+pub async fn load_project_history_command(
+    project_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running load project history command for project ID: {}",
+        project_id
+    );
+
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
+    let history_manager = HistoryManager::new(&repository_provider);
+
+    let events = history_manager
+        .load_project_history(project_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&events).unwrap())
+}
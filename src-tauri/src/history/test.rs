@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod history_test {
+    use crate::history::manager::HistoryManager;
+    use crate::history::{task_changes, HistoryField};
+    use crate::project::manager::ProjectsManager;
+    use crate::repository::RepositoryProvider;
+    use crate::task::manager::TaskManager;
+    use crate::task::{CreateTaskData, Task, UpdatedTaskData};
+
+    use chrono::{Duration, Utc};
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    #[test]
+    fn it_only_reports_the_fields_that_changed() {
+        let before = Task::new("Renew passport".to_string(), None, None, None, None);
+        let mut after = before.clone();
+        let due_date = Utc::now() + Duration::days(7);
+        after.due_at_utc = Some(due_date);
+        after.updated_at_utc = Utc::now() + Duration::minutes(1);
+
+        let events = task_changes(Some(&before), &after);
+
+        assert_eq!(1, events.len());
+        assert_eq!(HistoryField::DueAtUtc, events[0].field);
+        assert!(events[0].old_value.is_none());
+        assert_eq!(Some(due_date.to_rfc3339()), events[0].new_value);
+    }
+
+    #[tokio::test]
+    async fn it_records_the_history_of_a_task() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut projects_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        let project = projects_manager
+            .create_project("Travel".to_string(), None, None, None)
+            .await
+            .unwrap();
+
+        let task = task_manager
+            .create_task(CreateTaskData {
+                title: "Renew passport".to_string(),
+                description: None,
+                project_id: None,
+                due_at_utc: None,
+                priority: None,
+            })
+            .await
+            .unwrap();
+
+        task_manager
+            .update_task(
+                task.id,
+                UpdatedTaskData {
+                    title: "Renew passport".to_string(),
+                    description: None,
+                    due_date: Some((Utc::now() + Duration::days(3)).to_rfc3339()),
+                    project_id: Some(project.id.to_string()),
+                    priority: None,
+                },
+            )
+            .await
+            .unwrap();
+        task_manager.complete_task(task.id).await.unwrap();
+        task_manager.complete_task(task.id).await.unwrap();
+
+        let history_manager = HistoryManager::new(&provider);
+        let history = history_manager.load_task_history(task.id).await.unwrap();
+        let fields: Vec<HistoryField> = history.iter().map(|event| event.field.clone()).collect();
+
+        assert_eq!(
+            vec![
+                HistoryField::Created,
+                HistoryField::ProjectId,
+                HistoryField::DueAtUtc,
+                HistoryField::CompletedAtUtc,
+                HistoryField::CompletedAtUtc,
+            ],
+            fields
+        );
+        assert_eq!(Some(project.id.to_string()), history[1].new_value);
+        assert!(history[3].new_value.is_some());
+        assert!(history[4].new_value.is_none());
+    }
+
+    #[tokio::test]
+    async fn it_records_the_history_of_a_project() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut projects_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let project = projects_manager
+            .create_project("Travel".to_string(), None, None, None)
+            .await
+            .unwrap();
+        projects_manager.archive_project(project.id).await.unwrap();
+
+        let history_manager = HistoryManager::new(&provider);
+        let history = history_manager
+            .load_project_history(project.id)
+            .await
+            .unwrap();
+
+        assert_eq!(2, history.len());
+        assert_eq!(HistoryField::Created, history[0].field);
+        assert_eq!(HistoryField::ArchivedAtUtc, history[1].field);
+        assert!(history[1].old_value.is_none());
+    }
+}
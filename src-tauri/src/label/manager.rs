@@ -0,0 +1,141 @@
+use super::repository::LabelRepository;
+use super::Label;
+use crate::task::repository::TaskRepository;
+use crate::task::Task;
+use std::error::Error;
+use uuid::Uuid;
+
+pub struct LabelManager<'a> {
+    label_repository: &'a mut dyn LabelRepository,
+    task_repository: &'a mut dyn TaskRepository,
+}
+
+impl<'a> LabelManager<'a> {
+    pub fn new(
+        label_repository: &'a mut dyn LabelRepository,
+        task_repository: &'a mut dyn TaskRepository,
+    ) -> Self {
+        LabelManager {
+            label_repository,
+            task_repository,
+        }
+    }
+
+    pub async fn load_all(&mut self) -> Result<Vec<Label>, Box<dyn Error>> {
+        self.label_repository.find_all().await.map_err(|e| e.into())
+    }
+
+    pub async fn create_label(
+        &mut self,
+        name: String,
+        color: Option<String>,
+    ) -> Result<Label, Box<dyn Error>> {
+        let name = name.trim().to_string();
+        if name.is_empty() {
+            return Err("Label name cannot be empty".into());
+        }
+
+        if self.label_repository.find_by_name(&name).await?.is_some() {
+            return Err("A label with this name already exists".into());
+        }
+
+        let mut label = Label::new(name, color);
+        self.label_repository.save(&mut label).await?;
+
+        Ok(label)
+    }
+
+    pub async fn rename_label(
+        &mut self,
+        label_id: Uuid,
+        new_name: String,
+    ) -> Result<Label, Box<dyn Error>> {
+        let new_name = new_name.trim().to_string();
+        if new_name.is_empty() {
+            return Err("Label name cannot be empty".into());
+        }
+
+        let mut label = self
+            .label_repository
+            .find_by_id(label_id)
+            .await?
+            .ok_or("Label not found")?;
+
+        if let Some(existing) = self.label_repository.find_by_name(&new_name).await? {
+            if existing.id != label.id {
+                return Err("A label with this name already exists".into());
+            }
+        }
+
+        label.name = new_name;
+        self.label_repository.save(&mut label).await?;
+
+        Ok(label)
+    }
+
+    pub async fn delete_label(&mut self, label_id: Uuid) -> Result<(), Box<dyn Error>> {
+        let label = self
+            .label_repository
+            .find_by_id(label_id)
+            .await?
+            .ok_or("Label not found")?;
+
+        self.label_repository.delete(&label).await?;
+
+        Ok(())
+    }
+
+    pub async fn attach_label_to_task(
+        &mut self,
+        label_id: Uuid,
+        task_id: Uuid,
+    ) -> Result<(), Box<dyn Error>> {
+        self.label_repository
+            .find_by_id(label_id)
+            .await?
+            .ok_or("Label not found")?;
+        self.task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or("Task not found")?;
+
+        self.label_repository
+            .attach_to_task(label_id, task_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn detach_label_from_task(
+        &mut self,
+        label_id: Uuid,
+        task_id: Uuid,
+    ) -> Result<(), Box<dyn Error>> {
+        self.label_repository
+            .detach_from_task(label_id, task_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn load_labels_for_task(
+        &mut self,
+        task_id: Uuid,
+    ) -> Result<Vec<Label>, Box<dyn Error>> {
+        self.label_repository
+            .find_by_task(task_id)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    pub async fn load_tasks_for_label(
+        &mut self,
+        label_id: Uuid,
+        include_completed_tasks: bool,
+    ) -> Result<Vec<Task>, Box<dyn Error>> {
+        self.task_repository
+            .find_by_label(label_id, include_completed_tasks)
+            .await
+            .map_err(|e| e.into())
+    }
+}
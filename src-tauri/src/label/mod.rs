@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::{fmt::Hyphenated, Uuid};
+
+pub mod manager;
+pub mod repository;
+pub mod tauri;
+mod test;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Label {
+    #[sqlx(try_from = "Hyphenated")]
+    pub id: Uuid,
+    pub name: String,
+    pub color: Option<String>,
+    pub created_at_utc: DateTime<Utc>,
+    pub updated_at_utc: DateTime<Utc>,
+}
+
+impl Label {
+    pub fn new(name: String, color: Option<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::now_v7(),
+            name,
+            color,
+            created_at_utc: now,
+            updated_at_utc: now,
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{pool::PoolConnection, Sqlite};
+use uuid::Uuid;
+
+use super::Label;
+
+#[async_trait]
+pub trait LabelRepository: Send + Sync {
+    async fn save(&mut self, label: &mut Label) -> Result<(), sqlx::Error>;
+    async fn delete(&mut self, label: &Label) -> Result<(), sqlx::Error>;
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<Label>, sqlx::Error>;
+    async fn find_by_name(&mut self, name: &str) -> Result<Option<Label>, sqlx::Error>;
+    async fn find_all(&mut self) -> Result<Vec<Label>, sqlx::Error>;
+    async fn find_by_task(&mut self, task_id: Uuid) -> Result<Vec<Label>, sqlx::Error>;
+    async fn attach_to_task(&mut self, label_id: Uuid, task_id: Uuid) -> Result<(), sqlx::Error>;
+    async fn detach_from_task(&mut self, label_id: Uuid, task_id: Uuid) -> Result<(), sqlx::Error>;
+}
+
+pub struct SqliteLabelRepository {
+    connection: PoolConnection<Sqlite>,
+}
+
+impl SqliteLabelRepository {
+    pub fn new(connection: PoolConnection<Sqlite>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl LabelRepository for SqliteLabelRepository {
+    async fn save(&mut self, label: &mut Label) -> Result<(), sqlx::Error> {
+        let exists = sqlx::query("SELECT 1 FROM labels WHERE id = ?1 LIMIT 1")
+            .bind(label.id.to_string())
+            .fetch_optional(&mut *self.connection)
+            .await?
+            .is_some();
+
+        label.updated_at_utc = Utc::now();
+
+        if exists {
+            sqlx::query(
+                "UPDATE labels SET name = ?1, color = ?2, updated_at_utc = ?3 WHERE id = ?4",
+            )
+            .bind(&label.name)
+            .bind(&label.color)
+            .bind(label.updated_at_utc.to_rfc3339())
+            .bind(label.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+        } else {
+            sqlx::query(
+                "INSERT INTO labels (id, name, color, created_at_utc, updated_at_utc) VALUES (?1, ?2, ?3, ?4, ?5)",
+            )
+            .bind(label.id.to_string())
+            .bind(&label.name)
+            .bind(&label.color)
+            .bind(label.created_at_utc.to_rfc3339())
+            .bind(label.updated_at_utc.to_rfc3339())
+            .execute(&mut *self.connection)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&mut self, label: &Label) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM task_labels WHERE label_id = ?1")
+            .bind(label.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        sqlx::query("DELETE FROM labels WHERE id = ?1")
+            .bind(label.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<Label>, sqlx::Error> {
+        sqlx::query_as::<_, Label>("SELECT * FROM labels WHERE id = ?1 LIMIT 1")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.connection)
+            .await
+    }
+
+    async fn find_by_name(&mut self, name: &str) -> Result<Option<Label>, sqlx::Error> {
+        sqlx::query_as::<_, Label>("SELECT * FROM labels WHERE name = ?1 LIMIT 1")
+            .bind(name)
+            .fetch_optional(&mut *self.connection)
+            .await
+    }
+
+    async fn find_all(&mut self) -> Result<Vec<Label>, sqlx::Error> {
+        sqlx::query_as::<_, Label>("SELECT * FROM labels ORDER BY name ASC")
+            .fetch_all(&mut *self.connection)
+            .await
+    }
+
+    async fn find_by_task(&mut self, task_id: Uuid) -> Result<Vec<Label>, sqlx::Error> {
+        sqlx::query_as::<_, Label>(
+            "SELECT labels.* FROM labels INNER JOIN task_labels ON task_labels.label_id = labels.id WHERE task_labels.task_id = ?1 ORDER BY labels.name ASC",
+        )
+        .bind(task_id.to_string())
+        .fetch_all(&mut *self.connection)
+        .await
+    }
+
+    async fn attach_to_task(&mut self, label_id: Uuid, task_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT OR IGNORE INTO task_labels (task_id, label_id, created_at_utc) VALUES (?1, ?2, ?3)",
+        )
+        .bind(task_id.to_string())
+        .bind(label_id.to_string())
+        .bind(Utc::now().to_rfc3339())
+        .execute(&mut *self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn detach_from_task(&mut self, label_id: Uuid, task_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM task_labels WHERE task_id = ?1 AND label_id = ?2")
+            .bind(task_id.to_string())
+            .bind(label_id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::label::manager::LabelManager;
+use crate::repository::RepositoryProvider;
+
+#[tauri::command]
+pub async fn create_label_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    name: String,
+    color: Option<String>,
+) -> Result<String, String> {
+    log::debug!("Running create label command for: {}", name);
+
+    let mut label_repository = repository_provider
+        .label_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+    let label = label_manager
+        .create_label(name, color)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&label).unwrap())
+}
+
+#[tauri::command]
+pub async fn rename_label_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    label_id: String,
+    name: String,
+) -> Result<String, String> {
+    log::debug!("Running rename label command for: {} | {}", label_id, name);
+
+    let label_uuid = Uuid::parse_str(&label_id).map_err(|e| handle_error(&e))?;
+    let mut label_repository = repository_provider
+        .label_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+    let label = label_manager
+        .rename_label(label_uuid, name)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&label).unwrap())
+}
+
+#[tauri::command]
+pub async fn delete_label_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    label_id: String,
+) -> Result<String, String> {
+    log::debug!("Running delete label command for: {}", label_id);
+
+    let label_uuid = Uuid::parse_str(&label_id).map_err(|e| handle_error(&e))?;
+    let mut label_repository = repository_provider
+        .label_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+    label_manager
+        .delete_label(label_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(format!("Label with ID {} deleted successfully", &label_id))
+}
+
+#[tauri::command]
+pub async fn attach_label_to_task_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    label_id: String,
+    task_id: String,
+) -> Result<String, String> {
+    log::debug!(
+        "Running attach label command for: {} | task: {}",
+        label_id,
+        task_id
+    );
+
+    let label_uuid = Uuid::parse_str(&label_id).map_err(|e| handle_error(&e))?;
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let mut label_repository = repository_provider
+        .label_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+    label_manager
+        .attach_label_to_task(label_uuid, task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    let labels = label_manager
+        .load_labels_for_task(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&labels).unwrap())
+}
+
+#[tauri::command]
+pub async fn detach_label_from_task_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    label_id: String,
+    task_id: String,
+) -> Result<String, String> {
+    log::debug!(
+        "Running detach label command for: {} | task: {}",
+        label_id,
+        task_id
+    );
+
+    let label_uuid = Uuid::parse_str(&label_id).map_err(|e| handle_error(&e))?;
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let mut label_repository = repository_provider
+        .label_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+    label_manager
+        .detach_label_from_task(label_uuid, task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    let labels = label_manager
+        .load_labels_for_task(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&labels).unwrap())
+}
@@ -0,0 +1,85 @@
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::label::manager::LabelManager;
+use crate::repository::RepositoryProvider;
+
+#[tauri::command]
+pub async fn load_labels_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load labels command");
+
+    let mut label_repository = repository_provider
+        .label_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+    let labels = label_manager
+        .load_all()
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&labels).unwrap())
+}
+
+#[tauri::command]
+pub async fn load_labels_for_task_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    task_id: String,
+) -> Result<String, String> {
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let mut label_repository = repository_provider
+        .label_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+    let labels = label_manager
+        .load_labels_for_task(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&labels).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn load_tasks_by_label_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    label_id: String,
+    include_completed: bool,
+) -> Result<String, String> {
+    log::debug!(
+        "Running load tasks by label command - label_id: {:?}, include_completed: {:?}",
+        label_id,
+        include_completed
+    );
+
+    let label_uuid = Uuid::parse_str(&label_id).map_err(|e| handle_error(&e))?;
+    let mut label_repository = repository_provider
+        .label_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+    let tasks = label_manager
+        .load_tasks_for_label(label_uuid, include_completed)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&tasks).unwrap())
+}
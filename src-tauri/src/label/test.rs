@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod manager_test {
+    use crate::label::manager::LabelManager;
+    use crate::repository::RepositoryProvider;
+    use crate::task::manager::TaskManager;
+    use crate::task::CreateTaskData;
+
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    fn create_task_data(title: &str) -> CreateTaskData {
+        CreateTaskData {
+            title: title.to_string(),
+            description: None,
+            project_id: None,
+            due_at_utc: None,
+            priority: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_creates_and_renames_a_label() {
+        let provider = setup_test_db().await.unwrap();
+        let mut label_repository = provider.label_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+        let label = label_manager
+            .create_label("urgent".to_string(), None)
+            .await
+            .unwrap();
+
+        assert_eq!("urgent", label.name);
+
+        let renamed_label = label_manager
+            .rename_label(label.id, "very-urgent".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(label.id, renamed_label.id);
+        assert_eq!("very-urgent", renamed_label.name);
+    }
+
+    #[tokio::test]
+    async fn it_refuses_duplicate_label_names() {
+        let provider = setup_test_db().await.unwrap();
+        let mut label_repository = provider.label_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+        label_manager
+            .create_label("urgent".to_string(), None)
+            .await
+            .unwrap();
+
+        let duplicate = label_manager.create_label("urgent".to_string(), None).await;
+
+        assert!(duplicate.is_err());
+    }
+
+    #[tokio::test]
+    async fn it_attaches_and_detaches_labels_and_filters_tasks_by_label() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+
+        let open_task = task_manager
+            .create_task(create_task_data("Open Task"))
+            .await
+            .unwrap();
+        let completed_task = task_manager
+            .create_task(create_task_data("Completed Task"))
+            .await
+            .unwrap();
+        let unlabelled_task = task_manager
+            .create_task(create_task_data("Unlabelled Task"))
+            .await
+            .unwrap();
+        task_manager.complete_task(completed_task.id).await.unwrap();
+
+        let mut label_repository = provider.label_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+        let label = label_manager
+            .create_label("work".to_string(), None)
+            .await
+            .unwrap();
+
+        label_manager
+            .attach_label_to_task(label.id, open_task.id)
+            .await
+            .unwrap();
+        label_manager
+            .attach_label_to_task(label.id, completed_task.id)
+            .await
+            .unwrap();
+
+        let open_tasks = label_manager
+            .load_tasks_for_label(label.id, false)
+            .await
+            .unwrap();
+        assert_eq!(1, open_tasks.len());
+        assert_eq!(open_task.id, open_tasks[0].id);
+
+        let all_tasks = label_manager
+            .load_tasks_for_label(label.id, true)
+            .await
+            .unwrap();
+        assert_eq!(2, all_tasks.len());
+        assert!(all_tasks.iter().all(|task| task.id != unlabelled_task.id));
+
+        label_manager
+            .detach_label_from_task(label.id, open_task.id)
+            .await
+            .unwrap();
+
+        let labels = label_manager
+            .load_labels_for_task(open_task.id)
+            .await
+            .unwrap();
+        assert!(labels.is_empty());
+    }
+
+    #[tokio::test]
+    async fn deleting_a_label_removes_it_from_tasks() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+
+        let task = task_manager
+            .create_task(create_task_data("Task"))
+            .await
+            .unwrap();
+
+        let mut label_repository = provider.label_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+
+        let label = label_manager
+            .create_label("home".to_string(), None)
+            .await
+            .unwrap();
+        label_manager
+            .attach_label_to_task(label.id, task.id)
+            .await
+            .unwrap();
+
+        label_manager.delete_label(label.id).await.unwrap();
+
+        let labels = label_manager.load_labels_for_task(task.id).await.unwrap();
+        assert!(labels.is_empty());
+        assert!(label_manager.load_all().await.unwrap().is_empty());
+    }
+}
@@ -10,12 +10,20 @@ use tokio::runtime::Runtime;
 pub mod chart;
 pub mod configuration;
 pub mod errors;
+pub mod history;
+pub mod label;
 pub mod logger;
 pub mod ollama;
+pub mod pomodoro;
 pub mod project;
 pub mod recurring_task;
 pub mod repository;
+pub mod saved_view;
+pub mod search;
 pub mod task;
+pub mod template;
+pub mod time_entry;
+pub mod undo;
 
 use configuration::manager::ConfigurationManager;
 use configuration::manager::ConfigurationMode;
@@ -76,6 +84,19 @@ pub fn run() {
 
                     log::debug!("Migrations run successfully");
 
+                    // Failing to purge the trash should not prevent the app from starting
+                    let retention_days = configuration_manager.configuration.trash_retention_days;
+                    let repository_provider = repository::RepositoryProvider::new(db_pool.clone());
+                    match task::manager::TaskManager::new(&repository_provider)
+                        .purge_trash(retention_days)
+                        .await
+                    {
+                        Ok(purged_tasks) => {
+                            log::debug!("Purged {} tasks from the trash", purged_tasks)
+                        }
+                        Err(e) => log::error!("Failed to purge the trash: {}", e),
+                    }
+
                     Ok::<SqlitePool, AppError>(db_pool)
                 })
                 .map_err(|e| Box::new(e))?;
@@ -87,6 +108,7 @@ pub fn run() {
 
             app.manage(AsyncMutex::new(config_clone.configuration.clone()));
             app.manage(config_clone);
+            app.manage(pomodoro::engine::PomodoroEngine::new());
 
             Ok(())
         })
@@ -97,8 +119,18 @@ pub fn run() {
             project::tauri::actions::create_project_command,
             project::tauri::actions::update_project_command,
             project::tauri::actions::archive_project_command,
+            project::tauri::actions::unarchive_project_command,
+            project::tauri::actions::delete_project_command,
+            project::tauri::actions::demote_project_to_task_command,
+            project::tauri::actions::duplicate_project_command,
+            project::tauri::actions::create_section_command,
+            project::tauri::actions::rename_section_command,
+            project::tauri::actions::delete_section_command,
+            project::tauri::actions::reorder_sections_command,
+            project::tauri::actions::move_task_to_section_command,
             project::tauri::queries::load_projects_command,
             project::tauri::queries::load_project_details_command,
+            project::tauri::queries::load_sections_command,
             project::tauri::queries::load_favorite_projects_command,
             project::tauri::queries::count_open_tasks_for_project_command,
             project::tauri::queries::add_favorite_project_command,
@@ -107,21 +139,72 @@ pub fn run() {
             task::tauri::actions::create_task_command,
             task::tauri::actions::update_task_command,
             task::tauri::actions::delete_task_command,
+            task::tauri::actions::restore_task_command,
+            task::tauri::actions::empty_trash_command,
+            task::tauri::actions::unarchive_task_command,
+            task::tauri::actions::move_task_command,
+            task::tauri::actions::move_task_to_parent_command,
+            task::tauri::actions::set_task_auto_complete_command,
+            task::tauri::actions::set_task_estimate_command,
+            task::tauri::actions::duplicate_task_command,
             task::tauri::actions::complete_task_command,
             task::tauri::actions::create_subtask_for_task_command,
             task::tauri::actions::promote_task_to_project_command,
+            task::tauri::actions::add_task_dependency_command,
+            task::tauri::actions::remove_task_dependency_command,
             task::tauri::queries::load_tasks_command,
             task::tauri::queries::load_task_by_id_command,
             task::tauri::queries::load_tasks_inbox_command,
             task::tauri::queries::load_tasks_due_today_command,
             task::tauri::queries::load_completed_tasks_command,
             task::tauri::queries::load_subtasks_for_task_command,
+            task::tauri::queries::load_task_tree_command,
+            task::tauri::queries::load_project_task_trees_command,
             task::tauri::queries::load_completed_subtasks_for_task_command,
             task::tauri::queries::load_task_activity_statistics_command,
             task::tauri::queries::load_tasks_by_project_command,
+            task::tauri::queries::load_task_dependencies_command,
+            task::tauri::queries::load_actionable_tasks_command,
+            task::tauri::queries::load_tasks_by_filter_command,
+            task::tauri::queries::load_trash_command,
+            task::tauri::queries::load_archived_tasks_command,
+            // Label commands
+            label::tauri::actions::create_label_command,
+            label::tauri::actions::rename_label_command,
+            label::tauri::actions::delete_label_command,
+            label::tauri::actions::attach_label_to_task_command,
+            label::tauri::actions::detach_label_from_task_command,
+            label::tauri::queries::load_labels_command,
+            label::tauri::queries::load_labels_for_task_command,
+            label::tauri::queries::load_tasks_by_label_command,
             // Chart commands
             chart::tauri::queries::load_rolling_week_day_charts_command,
             chart::tauri::queries::load_project_activity_stats_command,
+            chart::tauri::queries::load_task_time_stats_command,
+            chart::tauri::queries::load_project_time_stats_command,
+            pomodoro::tauri::actions::start_pomodoro_command,
+            pomodoro::tauri::actions::skip_pomodoro_phase_command,
+            pomodoro::tauri::actions::stop_pomodoro_command,
+            pomodoro::tauri::queries::load_pomodoro_state_command,
+            pomodoro::tauri::queries::load_pomodoro_sessions_command,
+            // Saved view commands
+            saved_view::tauri::actions::create_view_command,
+            saved_view::tauri::actions::update_view_command,
+            saved_view::tauri::actions::delete_view_command,
+            saved_view::tauri::queries::load_views_command,
+            saved_view::tauri::queries::load_view_tasks_command,
+            template::tauri::actions::save_project_as_template_command,
+            template::tauri::actions::delete_template_command,
+            template::tauri::actions::create_project_from_template_command,
+            template::tauri::queries::load_templates_command,
+            time_entry::tauri::actions::start_timer_command,
+            time_entry::tauri::actions::stop_timer_command,
+            time_entry::tauri::actions::add_time_entry_command,
+            time_entry::tauri::actions::delete_time_entry_command,
+            time_entry::tauri::queries::load_running_timer_command,
+            time_entry::tauri::queries::load_time_entries_command,
+            // Search commands
+            search::tauri::queries::search_command,
             // Configuration commands
             configuration::tauri::queries::load_configuration_command,
             // Ollama commands
@@ -131,7 +214,17 @@ pub fn run() {
             recurring_task::tauri::actions::setup_recurring_task_command,
             recurring_task::tauri::actions::update_recurring_task_command,
             recurring_task::tauri::actions::delete_recurring_task_command,
+            recurring_task::tauri::actions::skip_occurrence_command,
+            recurring_task::tauri::actions::postpone_occurrence_command,
             recurring_task::tauri::queries::get_recurring_task_command,
+            recurring_task::tauri::queries::preview_recurrence_command,
+            // History commands
+            history::tauri::queries::load_task_history_command,
+            history::tauri::queries::load_project_history_command,
+            // Undo commands
+            undo::tauri::actions::undo_command,
+            undo::tauri::actions::redo_command,
+            undo::tauri::queries::load_undo_state_command,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -46,6 +46,7 @@ You are an expert task prioritizer. You will be given a list of tasks with the f
 
     Task descriptions
     Associated project (if any)
+    Priority (P1 is the most urgent, P4 the least)
     Due dates
     Creation dates
     Last updated dates
@@ -54,6 +55,7 @@ You are an expert task prioritizer. You will be given a list of tasks with the f
 Your job is to analyze this list of tasks and provide a prioritized list based on the following criteria:
 
     Due Date: Tasks that are closer to their due date should be prioritized higher.
+    Priority: Tasks the user marked with a higher priority (P1, P2) should be prioritized higher.
     Project Context: If the task is part of a larger project, prioritize those tasks to ensure progress on the overall project.
     Created Date: Tasks created more recently may need attention sooner, especially if there is no due date.
     Last Updated Date: If a task hasn't been updated recently, it may require more immediate attention.
@@ -129,6 +131,7 @@ You are a task prioritizer focused on helping someone find a quick task to compl
 
     Task descriptions
     Associated projects (if any)
+    Priority (P1 is the most urgent, P4 the least)
     Due dates
     Creation dates
     Last updated dates
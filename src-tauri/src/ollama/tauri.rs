@@ -186,6 +186,8 @@ fn format_single_task(
         task_text.push_str(&format!("Description: {}\n", desc));
     }
 
+    task_text.push_str(&format!("Priority: {}\n", task.priority));
+
     if let Some(due_date) = task.due_at_utc {
         task_text.push_str(&format!("Due: {}\n", due_date.format("%Y-%m-%d")));
     }
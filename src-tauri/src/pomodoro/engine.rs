@@ -0,0 +1,150 @@
+use chrono::Utc;
+use std::error::Error;
+use tauri::async_runtime::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+use super::repository::PomodoroSessionRepository;
+use super::{PomodoroConfig, PomodoroPhase, PomodoroSession, PomodoroState, PHASE_CHANGED_EVENT};
+use crate::configuration::Configuration;
+use crate::repository::RepositoryProvider;
+use crate::task::repository::TaskRepository;
+
+struct EngineState {
+    state: PomodoroState,
+    // Bumped on every phase change so timers of earlier phases do nothing
+    generation: u64,
+}
+
+/// Runs the Pomodoro cycle in the backend, the window only reflects its
+/// state and can be reloaded at any time.
+pub struct PomodoroEngine {
+    inner: Mutex<EngineState>,
+}
+
+impl Default for PomodoroEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PomodoroEngine {
+    pub fn new() -> Self {
+        Self {
+            inner: Mutex::new(EngineState {
+                state: PomodoroState::idle(),
+                generation: 0,
+            }),
+        }
+    }
+
+    pub async fn state(&self) -> PomodoroState {
+        self.inner.lock().await.state.clone()
+    }
+
+    pub async fn start(
+        &self,
+        app: &AppHandle,
+        task_id: Option<Uuid>,
+    ) -> Result<PomodoroState, Box<dyn Error>> {
+        if let Some(task_id) = task_id {
+            app.state::<RepositoryProvider>()
+                .task_repository()
+                .await?
+                .find_by_id(task_id)
+                .await?
+                .ok_or("Task not found")?;
+        }
+
+        let config = load_config(app).await;
+        let mut inner = self.inner.lock().await;
+        let state = PomodoroState::start(&config, task_id, Utc::now());
+        Ok(self.change_phase(app, &mut inner, state))
+    }
+
+    pub async fn skip(&self, app: &AppHandle) -> PomodoroState {
+        let config = load_config(app).await;
+        let mut inner = self.inner.lock().await;
+        if inner.state.phase == PomodoroPhase::Idle {
+            return inner.state.clone();
+        }
+
+        let state = inner.state.skip_phase(&config, Utc::now());
+        self.change_phase(app, &mut inner, state)
+    }
+
+    // The work phase in progress is dropped, only full sessions are recorded
+    pub async fn stop(&self, app: &AppHandle) -> PomodoroState {
+        let mut inner = self.inner.lock().await;
+        self.change_phase(app, &mut inner, PomodoroState::idle())
+    }
+
+    async fn complete_phase(&self, app: &AppHandle, generation: u64) {
+        let config = load_config(app).await;
+        let mut inner = self.inner.lock().await;
+        if inner.generation != generation {
+            return;
+        }
+
+        let finished_state = inner.state.clone();
+        if finished_state.phase == PomodoroPhase::Work {
+            if let Err(e) = record_session(app, &finished_state).await {
+                log::error!("Failed to record the pomodoro session: {}", e);
+            }
+        }
+
+        let state = finished_state.complete_phase(&config, Utc::now());
+        self.change_phase(app, &mut inner, state);
+    }
+
+    fn change_phase(
+        &self,
+        app: &AppHandle,
+        inner: &mut EngineState,
+        state: PomodoroState,
+    ) -> PomodoroState {
+        inner.generation += 1;
+        inner.state = state.clone();
+
+        if let Err(e) = app.emit(PHASE_CHANGED_EVENT, &state) {
+            log::error!("Failed to emit the pomodoro phase change: {}", e);
+        }
+
+        if let Some(ends_at_utc) = state.phase_ends_at_utc {
+            let app = app.clone();
+            let generation = inner.generation;
+            let wait = (ends_at_utc - Utc::now()).to_std().unwrap_or_default();
+            tauri::async_runtime::spawn(async move {
+                tokio::time::sleep(wait).await;
+                app.state::<PomodoroEngine>()
+                    .complete_phase(&app, generation)
+                    .await;
+            });
+        }
+
+        state
+    }
+}
+
+async fn load_config(app: &AppHandle) -> PomodoroConfig {
+    app.state::<Mutex<Configuration>>()
+        .lock()
+        .await
+        .pomodoro
+        .clone()
+}
+
+async fn record_session(app: &AppHandle, state: &PomodoroState) -> Result<(), sqlx::Error> {
+    let (Some(started_at_utc), Some(ended_at_utc)) =
+        (state.phase_started_at_utc, state.phase_ends_at_utc)
+    else {
+        return Ok(());
+    };
+
+    let session = PomodoroSession::new(state.task_id, started_at_utc, ended_at_utc);
+    app.state::<RepositoryProvider>()
+        .pomodoro_session_repository()
+        .await?
+        .save(&session)
+        .await
+}
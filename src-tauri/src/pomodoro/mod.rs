@@ -0,0 +1,158 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub mod engine;
+pub mod repository;
+pub mod tauri;
+mod test;
+
+pub const PHASE_CHANGED_EVENT: &str = "pomodoro-phase-changed";
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PomodoroConfig {
+    #[serde(rename = "workMinutes")]
+    pub work_minutes: u32,
+    #[serde(rename = "shortBreakMinutes")]
+    pub short_break_minutes: u32,
+    #[serde(rename = "longBreakMinutes")]
+    pub long_break_minutes: u32,
+    // Work sessions completed before a long break replaces the short one
+    #[serde(rename = "sessionsBeforeLongBreak")]
+    pub sessions_before_long_break: u32,
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        Self {
+            work_minutes: 25,
+            short_break_minutes: 5,
+            long_break_minutes: 15,
+            sessions_before_long_break: 4,
+        }
+    }
+}
+
+impl PomodoroConfig {
+    fn duration_of(&self, phase: PomodoroPhase) -> Duration {
+        let minutes = match phase {
+            PomodoroPhase::Idle => 0,
+            PomodoroPhase::Work => self.work_minutes,
+            PomodoroPhase::ShortBreak => self.short_break_minutes,
+            PomodoroPhase::LongBreak => self.long_break_minutes,
+        };
+        Duration::minutes(minutes.into())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, strum_macros::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PomodoroPhase {
+    Idle,
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PomodoroState {
+    pub phase: PomodoroPhase,
+    // Task the focus sessions are tracked against, kept through the breaks
+    pub task_id: Option<Uuid>,
+    pub phase_started_at_utc: Option<DateTime<Utc>>,
+    pub phase_ends_at_utc: Option<DateTime<Utc>>,
+    // Work sessions completed since the last long break
+    pub completed_work_sessions: u32,
+}
+
+impl PomodoroState {
+    pub fn idle() -> Self {
+        Self {
+            phase: PomodoroPhase::Idle,
+            task_id: None,
+            phase_started_at_utc: None,
+            phase_ends_at_utc: None,
+            completed_work_sessions: 0,
+        }
+    }
+
+    pub fn start(config: &PomodoroConfig, task_id: Option<Uuid>, now: DateTime<Utc>) -> Self {
+        Self {
+            task_id,
+            ..Self::idle()
+        }
+        .enter(config, PomodoroPhase::Work, now)
+    }
+
+    /// Moves on once the current phase ran its full length.
+    pub fn complete_phase(&self, config: &PomodoroConfig, now: DateTime<Utc>) -> Self {
+        self.advance(config, now, true)
+    }
+
+    /// Moves on before the current phase is over, a skipped work phase does
+    /// not count towards the long break.
+    pub fn skip_phase(&self, config: &PomodoroConfig, now: DateTime<Utc>) -> Self {
+        self.advance(config, now, false)
+    }
+
+    fn advance(&self, config: &PomodoroConfig, now: DateTime<Utc>, finished: bool) -> Self {
+        match self.phase {
+            PomodoroPhase::Idle => self.clone(),
+            PomodoroPhase::Work => {
+                let completed_work_sessions = self.completed_work_sessions + finished as u32;
+                if finished && completed_work_sessions >= config.sessions_before_long_break {
+                    Self {
+                        completed_work_sessions: 0,
+                        ..self.clone()
+                    }
+                    .enter(config, PomodoroPhase::LongBreak, now)
+                } else {
+                    Self {
+                        completed_work_sessions,
+                        ..self.clone()
+                    }
+                    .enter(config, PomodoroPhase::ShortBreak, now)
+                }
+            }
+            PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => {
+                self.enter(config, PomodoroPhase::Work, now)
+            }
+        }
+    }
+
+    fn enter(&self, config: &PomodoroConfig, phase: PomodoroPhase, now: DateTime<Utc>) -> Self {
+        Self {
+            phase,
+            phase_started_at_utc: Some(now),
+            phase_ends_at_utc: Some(now + config.duration_of(phase)),
+            ..self.clone()
+        }
+    }
+}
+
+// A work phase that ran its full length
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PomodoroSession {
+    pub id: Uuid,
+    pub task_id: Option<Uuid>,
+    pub started_at_utc: DateTime<Utc>,
+    pub ended_at_utc: DateTime<Utc>,
+    pub created_at_utc: DateTime<Utc>,
+}
+
+impl PomodoroSession {
+    pub fn new(
+        task_id: Option<Uuid>,
+        started_at_utc: DateTime<Utc>,
+        ended_at_utc: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            task_id,
+            started_at_utc,
+            ended_at_utc,
+            created_at_utc: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{pool::PoolConnection, Row, Sqlite};
+use uuid::Uuid;
+
+use super::PomodoroSession;
+
+#[async_trait]
+pub trait PomodoroSessionRepository: Send + Sync {
+    async fn save(&mut self, session: &PomodoroSession) -> Result<(), sqlx::Error>;
+    async fn find_by_task(&mut self, task_id: Uuid) -> Result<Vec<PomodoroSession>, sqlx::Error>;
+}
+
+pub struct SqlitePomodoroSessionRepository {
+    connection: PoolConnection<Sqlite>,
+}
+
+impl SqlitePomodoroSessionRepository {
+    pub fn new(connection: PoolConnection<Sqlite>) -> Self {
+        Self { connection }
+    }
+
+    fn row_to_session(row: sqlx::sqlite::SqliteRow) -> Result<PomodoroSession, sqlx::Error> {
+        let parse_date = |column: &str| {
+            DateTime::parse_from_rfc3339(row.get(column))
+                .map(|date| date.with_timezone(&Utc))
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))
+        };
+
+        Ok(PomodoroSession {
+            id: Uuid::parse_str(row.get("id")).map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            task_id: row
+                .get::<Option<String>, _>("task_id")
+                .map(|id| Uuid::parse_str(&id))
+                .transpose()
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+            started_at_utc: parse_date("started_at_utc")?,
+            ended_at_utc: parse_date("ended_at_utc")?,
+            created_at_utc: parse_date("created_at_utc")?,
+        })
+    }
+}
+
+#[async_trait]
+impl PomodoroSessionRepository for SqlitePomodoroSessionRepository {
+    async fn save(&mut self, session: &PomodoroSession) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO pomodoro_sessions (id, task_id, started_at_utc, ended_at_utc, created_at_utc) VALUES (?1, ?2, ?3, ?4, ?5)",
+        )
+        .bind(session.id.to_string())
+        .bind(session.task_id.map(|id| id.to_string()))
+        .bind(session.started_at_utc.to_rfc3339())
+        .bind(session.ended_at_utc.to_rfc3339())
+        .bind(session.created_at_utc.to_rfc3339())
+        .execute(&mut *self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_task(&mut self, task_id: Uuid) -> Result<Vec<PomodoroSession>, sqlx::Error> {
+        sqlx::query(
+            "SELECT * FROM pomodoro_sessions WHERE task_id = ?1 ORDER BY started_at_utc DESC",
+        )
+        .bind(task_id.to_string())
+        .fetch_all(&mut *self.connection)
+        .await?
+        .into_iter()
+        .map(Self::row_to_session)
+        .collect()
+    }
+}
@@ -0,0 +1,50 @@
+use tauri::{AppHandle, State};
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::pomodoro::engine::PomodoroEngine;
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn start_pomodoro_command(
+    app: AppHandle,
+    engine: State<'_, PomodoroEngine>,
+    task_id: Option<String>,
+) -> Result<String, String> {
+    log::debug!("Running start pomodoro command for: {:?}", task_id);
+
+    let task_uuid = task_id
+        .map(|task_id| Uuid::parse_str(&task_id))
+        .transpose()
+        .map_err(|e| handle_error(&e))?;
+
+    let state = engine
+        .start(&app, task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&state).unwrap())
+}
+
+#[tauri::command]
+pub async fn skip_pomodoro_phase_command(
+    app: AppHandle,
+    engine: State<'_, PomodoroEngine>,
+) -> Result<String, String> {
+    log::debug!("Running skip pomodoro phase command");
+
+    let state = engine.skip(&app).await;
+
+    Ok(serde_json::to_string(&state).unwrap())
+}
+
+#[tauri::command]
+pub async fn stop_pomodoro_command(
+    app: AppHandle,
+    engine: State<'_, PomodoroEngine>,
+) -> Result<String, String> {
+    log::debug!("Running stop pomodoro command");
+
+    let state = engine.stop(&app).await;
+
+    Ok(serde_json::to_string(&state).unwrap())
+}
@@ -0,0 +1,39 @@
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::pomodoro::engine::PomodoroEngine;
+use crate::pomodoro::repository::PomodoroSessionRepository;
+use crate::repository::RepositoryProvider;
+
+// Lets a reloaded window pick the running cycle back up
+#[tauri::command]
+pub async fn load_pomodoro_state_command(
+    engine: State<'_, PomodoroEngine>,
+) -> Result<String, String> {
+    log::debug!("Running load pomodoro state command");
+
+    let state = engine.state().await;
+
+    Ok(serde_json::to_string(&state).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn load_pomodoro_sessions_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    task_id: String,
+) -> Result<String, String> {
+    log::debug!("Running load pomodoro sessions command for: {}", task_id);
+
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let sessions = repository_provider
+        .pomodoro_session_repository()
+        .await
+        .map_err(|e| handle_error(&e))?
+        .find_by_task(task_uuid)
+        .await
+        .map_err(|e| handle_error(&e))?;
+
+    Ok(serde_json::to_string(&sessions).unwrap())
+}
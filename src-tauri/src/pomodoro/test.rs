@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod state_test {
+    use crate::pomodoro::{PomodoroConfig, PomodoroPhase, PomodoroState};
+
+    use chrono::{Duration, TimeZone, Utc};
+    use uuid::Uuid;
+
+    #[test]
+    fn it_alternates_work_and_breaks_with_a_long_break_every_few_sessions() {
+        let config = PomodoroConfig {
+            sessions_before_long_break: 2,
+            ..PomodoroConfig::default()
+        };
+        let now = Utc.with_ymd_and_hms(2025, 3, 3, 9, 0, 0).unwrap();
+        let task_id = Uuid::now_v7();
+
+        let state = PomodoroState::start(&config, Some(task_id), now);
+        assert_eq!(PomodoroPhase::Work, state.phase);
+        assert_eq!(Some(now + Duration::minutes(25)), state.phase_ends_at_utc);
+
+        let state = state.complete_phase(&config, now);
+        assert_eq!(PomodoroPhase::ShortBreak, state.phase);
+        assert_eq!(1, state.completed_work_sessions);
+        assert_eq!(Some(now + Duration::minutes(5)), state.phase_ends_at_utc);
+
+        let state = state.complete_phase(&config, now);
+        assert_eq!(PomodoroPhase::Work, state.phase);
+        assert_eq!(Some(task_id), state.task_id);
+
+        let state = state.complete_phase(&config, now);
+        assert_eq!(PomodoroPhase::LongBreak, state.phase);
+        assert_eq!(0, state.completed_work_sessions);
+        assert_eq!(Some(now + Duration::minutes(15)), state.phase_ends_at_utc);
+    }
+
+    #[test]
+    fn skipped_work_does_not_count_towards_the_long_break() {
+        let config = PomodoroConfig {
+            sessions_before_long_break: 1,
+            ..PomodoroConfig::default()
+        };
+        let now = Utc::now();
+
+        let state = PomodoroState::start(&config, None, now).skip_phase(&config, now);
+        assert_eq!(PomodoroPhase::ShortBreak, state.phase);
+        assert_eq!(0, state.completed_work_sessions);
+
+        assert_eq!(
+            PomodoroState::idle(),
+            PomodoroState::idle().complete_phase(&config, now)
+        );
+    }
+}
+
+#[cfg(test)]
+mod repository_test {
+    use crate::pomodoro::repository::PomodoroSessionRepository;
+    use crate::pomodoro::PomodoroSession;
+    use crate::repository::RepositoryProvider;
+
+    use chrono::{Duration, Utc};
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+    use uuid::Uuid;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    #[tokio::test]
+    async fn it_persists_sessions_linked_to_a_task() {
+        let provider = setup_test_db().await.unwrap();
+        let mut repository = provider.pomodoro_session_repository().await.unwrap();
+        let task_id = Uuid::now_v7();
+        let started_at = Utc::now() - Duration::minutes(25);
+
+        repository
+            .save(&PomodoroSession::new(Some(task_id), started_at, Utc::now()))
+            .await
+            .unwrap();
+        repository
+            .save(&PomodoroSession::new(None, started_at, Utc::now()))
+            .await
+            .unwrap();
+
+        let sessions = repository.find_by_task(task_id).await.unwrap();
+        assert_eq!(1, sessions.len());
+        assert_eq!(Some(task_id), sessions[0].task_id);
+        assert_eq!(started_at, sessions[0].started_at_utc);
+    }
+}
@@ -1,24 +1,35 @@
 use super::repository::ProjectRepository;
 use super::Project;
 use super::ProjectDetail;
+use super::ProjectTaskDisposal;
+use super::{Section, SectionDetail};
+use crate::task::position;
 use crate::task::repository::TaskRepository;
-use chrono::Utc;
+use crate::task::{Task, TaskStatus};
+use crate::template::ProjectTemplate;
+use crate::undo::repository::UndoRepository;
+use crate::undo::UndoOperation;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
 use std::error::Error;
 use uuid::Uuid;
 
 pub struct ProjectsManager<'a> {
     project_repository: &'a mut dyn ProjectRepository,
     task_repository: &'a mut dyn TaskRepository,
+    undo_repository: &'a mut dyn UndoRepository,
 }
 
 impl<'a> ProjectsManager<'a> {
     pub fn new(
         project_repository: &'a mut dyn ProjectRepository,
         task_repository: &'a mut dyn TaskRepository,
+        undo_repository: &'a mut dyn UndoRepository,
     ) -> Self {
         ProjectsManager {
             project_repository,
             task_repository,
+            undo_repository,
         }
     }
 
@@ -49,11 +60,203 @@ impl<'a> ProjectsManager<'a> {
             .task_repository
             .find_by_project(project_id, include_completed_tasks)
             .await?;
+        let sections = self.project_repository.find_sections(project_id).await?;
 
-        let project_detail = ProjectDetail { project, tasks };
+        let mut sections: Vec<SectionDetail> = sections
+            .into_iter()
+            .map(|section| SectionDetail {
+                section,
+                tasks: Vec::new(),
+            })
+            .collect();
+        let mut unsectioned_tasks = Vec::new();
+
+        for task in tasks {
+            let section = sections
+                .iter_mut()
+                .find(|detail| Some(detail.section.id) == task.section_id);
+            match section {
+                Some(section) => section.tasks.push(task),
+                None => unsectioned_tasks.push(task),
+            }
+        }
+
+        let project_detail = ProjectDetail {
+            project,
+            sections,
+            unsectioned_tasks,
+        };
         Ok(project_detail)
     }
 
+    pub async fn load_sections(
+        &mut self,
+        project_id: Uuid,
+    ) -> Result<Vec<Section>, Box<dyn Error>> {
+        self.project_repository
+            .find_sections(project_id)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    pub async fn create_section(
+        &mut self,
+        project_id: Uuid,
+        title: String,
+    ) -> Result<Section, Box<dyn Error>> {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            return Err("Section title cannot be empty".into());
+        }
+
+        self.project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or("Project not found")?;
+
+        let position = self
+            .project_repository
+            .find_sections(project_id)
+            .await?
+            .last()
+            .map_or(0, |section| section.position + 1);
+
+        let section = Section::new(project_id, title, position);
+
+        let mut operation = UndoOperation::new("Create section");
+        operation.record_section(None, Some(section.clone()));
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(section)
+    }
+
+    pub async fn rename_section(
+        &mut self,
+        section_id: Uuid,
+        new_title: String,
+    ) -> Result<Section, Box<dyn Error>> {
+        let new_title = new_title.trim().to_string();
+        if new_title.is_empty() {
+            return Err("Section title cannot be empty".into());
+        }
+
+        let mut section = self
+            .project_repository
+            .find_section_by_id(section_id)
+            .await?
+            .ok_or("Section not found")?;
+
+        let section_before = section.clone();
+        section.title = new_title;
+        section.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Rename section");
+        operation.record_section(Some(section_before), Some(section.clone()));
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(section)
+    }
+
+    // The tasks of a deleted section stay in the project, without a section
+    pub async fn delete_section(&mut self, section_id: Uuid) -> Result<(), Box<dyn Error>> {
+        let section = self
+            .project_repository
+            .find_section_by_id(section_id)
+            .await?
+            .ok_or("Section not found")?;
+
+        let mut operation = UndoOperation::new("Delete section");
+        let now = Utc::now();
+        let tasks = self
+            .task_repository
+            .find_all_by_project(section.project_id)
+            .await?;
+        for task in tasks
+            .into_iter()
+            .filter(|task| task.section_id == Some(section.id))
+        {
+            let mut task_after = task.clone();
+            task_after.section_id = None;
+            task_after.updated_at_utc = now;
+            operation.record_task(Some(task), Some(task_after));
+        }
+        operation.record_section(Some(section), None);
+
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(())
+    }
+
+    pub async fn reorder_sections(
+        &mut self,
+        project_id: Uuid,
+        section_ids: Vec<Uuid>,
+    ) -> Result<Vec<Section>, Box<dyn Error>> {
+        let sections = self.project_repository.find_sections(project_id).await?;
+
+        let is_complete_ordering = section_ids.len() == sections.len()
+            && sections
+                .iter()
+                .all(|section| section_ids.contains(&section.id));
+        if !is_complete_ordering {
+            return Err("The new order must list every section of the project once".into());
+        }
+
+        let mut operation = UndoOperation::new("Reorder sections");
+        let now = Utc::now();
+        let mut reordered_sections = Vec::new();
+        for (position, section_id) in section_ids.iter().enumerate() {
+            let section_before = sections
+                .iter()
+                .find(|section| section.id == *section_id)
+                .cloned()
+                .ok_or("Section not found")?;
+            let mut section = section_before.clone();
+            section.position = position as i64;
+            section.updated_at_utc = now;
+            operation.record_section(Some(section_before), Some(section.clone()));
+            reordered_sections.push(section);
+        }
+
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(reordered_sections)
+    }
+
+    pub async fn move_task_to_section(
+        &mut self,
+        task_id: Uuid,
+        section_id: Option<Uuid>,
+    ) -> Result<Task, Box<dyn Error>> {
+        let mut task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or("Task not found")?;
+
+        if let Some(section_id) = section_id {
+            let section = self
+                .project_repository
+                .find_section_by_id(section_id)
+                .await?
+                .ok_or("Section not found")?;
+
+            if task.project_id != Some(section.project_id) {
+                return Err("Task and section belong to different projects".into());
+            }
+        }
+
+        let task_before = task.clone();
+        task.section_id = section_id;
+        task.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Move task to section");
+        operation.record_task(Some(task_before), Some(task.clone()));
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(task)
+    }
+
     pub async fn create_project(
         &mut self,
         title: String,
@@ -61,7 +264,7 @@ impl<'a> ProjectsManager<'a> {
         color: Option<String>,
         description: Option<String>,
     ) -> Result<Project, Box<dyn Error>> {
-        let mut project = Project {
+        let project = Project {
             id: Uuid::now_v7(),
             title,
             emoji,
@@ -73,7 +276,9 @@ impl<'a> ProjectsManager<'a> {
             is_favorite: false,
         };
 
-        self.project_repository.save(&mut project).await?;
+        let mut operation = UndoOperation::new("Create project");
+        operation.record_project(None, Some(project.clone()));
+        self.undo_repository.commit(&operation).await?;
 
         Ok(project)
     }
@@ -91,6 +296,7 @@ impl<'a> ProjectsManager<'a> {
             .find_by_id(project_id)
             .await?
             .ok_or("Project not found")?;
+        let project_before = project.clone();
 
         project.title = new_title;
         project.emoji = new_emoji;
@@ -98,7 +304,9 @@ impl<'a> ProjectsManager<'a> {
         project.description = new_description;
         project.updated_at_utc = Utc::now();
 
-        self.project_repository.save(&mut project).await?;
+        let mut operation = UndoOperation::new("Update project");
+        operation.record_project(Some(project_before), Some(project.clone()));
+        self.undo_repository.commit(&operation).await?;
 
         Ok(project)
     }
@@ -109,11 +317,337 @@ impl<'a> ProjectsManager<'a> {
             .find_by_id(project_id)
             .await?
             .ok_or("Project not found")?;
+        let project_before = project.clone();
 
         project.archived_at_utc = Some(Utc::now());
         project.updated_at_utc = Utc::now();
 
-        self.project_repository.save(&mut project).await?;
+        let mut operation = UndoOperation::new("Archive project");
+        operation.record_project(Some(project_before), Some(project.clone()));
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(project)
+    }
+
+    pub async fn unarchive_project(&mut self, project_id: Uuid) -> Result<Project, Box<dyn Error>> {
+        let mut project = self
+            .project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or("Project not found")?;
+
+        if project.archived_at_utc.is_none() {
+            return Err("Project is not archived".into());
+        }
+        let project_before = project.clone();
+
+        project.archived_at_utc = None;
+        project.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Unarchive project");
+        operation.record_project(Some(project_before), Some(project.clone()));
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(project)
+    }
+
+    pub async fn delete_project(
+        &mut self,
+        project_id: Uuid,
+        task_disposal: ProjectTaskDisposal,
+    ) -> Result<(), Box<dyn Error>> {
+        let project = self
+            .project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or("Project not found")?;
+
+        let mut operation = UndoOperation::new("Delete project");
+        let mut tasks_before = self.task_repository.find_all_by_project(project_id).await?;
+        // Top-level tasks are placed at the end of the inbox in their current order
+        tasks_before.sort_by(|a, b| a.position.cmp(&b.position));
+
+        // Tasks always leave the project first, so restoring them from the trash lands them in the inbox
+        let now = Utc::now();
+        let mut last_position: Option<String> = None;
+        for task in tasks_before {
+            let mut task_after = task.clone();
+            task_after.project_id = None;
+            task_after.section_id = None;
+            task_after.updated_at_utc = now;
+            if task_after.parent_task_id.is_none() {
+                task_after.position = match &last_position {
+                    Some(last_position) => position::rank_between(Some(last_position), None)?,
+                    None => self.task_repository.position_at_end(&task_after).await?,
+                };
+                last_position = Some(task_after.position.clone());
+            }
+            // Tasks already in the trash keep their own timestamp so they are restored separately
+            if task_disposal == ProjectTaskDisposal::TrashTasks
+                && task_after.deleted_at_utc.is_none()
+            {
+                task_after.deleted_at_utc = Some(now);
+            }
+            operation.record_task(Some(task), Some(task_after));
+        }
+
+        // The project's sections go along with it
+        for section in self.project_repository.find_sections(project_id).await? {
+            operation.record_section(Some(section), None);
+        }
+        operation.record_project(Some(project), None);
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(())
+    }
+
+    /// Copies a project with its sections and open tasks, shifting the due
+    /// dates of the copies by `due_date_offset` when given.
+    pub async fn duplicate_project(
+        &mut self,
+        project_id: Uuid,
+        due_date_offset: Option<Duration>,
+    ) -> Result<Project, Box<dyn Error>> {
+        let project = self
+            .project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or("Project not found")?;
+
+        let now = Utc::now();
+        let project_copy = Project {
+            id: Uuid::now_v7(),
+            created_at_utc: now,
+            updated_at_utc: now,
+            archived_at_utc: None,
+            is_favorite: false,
+            ..project
+        };
+
+        let mut operation = UndoOperation::new("Duplicate project");
+        operation.record_project(None, Some(project_copy.clone()));
+
+        let mut copied_section_ids: HashMap<Uuid, Uuid> = HashMap::new();
+        for section in self.project_repository.find_sections(project_id).await? {
+            let section_copy = Section::new(project_copy.id, section.title, section.position);
+            copied_section_ids.insert(section.id, section_copy.id);
+            operation.record_section(None, Some(section_copy));
+        }
+
+        let open_tasks: Vec<Task> = self
+            .task_repository
+            .find_all_by_project(project_id)
+            .await?
+            .into_iter()
+            .filter(|task| task.status() == TaskStatus::Open && task.deleted_at_utc.is_none())
+            .collect();
+        let copied_task_ids: HashMap<Uuid, Uuid> = open_tasks
+            .iter()
+            .map(|task| (task.id, Uuid::now_v7()))
+            .collect();
+
+        for task in &open_tasks {
+            let mut task_copy = task.duplicate();
+            task_copy.id = copied_task_ids[&task.id];
+            task_copy.project_id = Some(project_copy.id);
+            // Subtasks of a completed task are lifted to the top of the copy
+            task_copy.parent_task_id = task
+                .parent_task_id
+                .and_then(|parent_task_id| copied_task_ids.get(&parent_task_id).copied());
+            task_copy.section_id = task
+                .section_id
+                .and_then(|section_id| copied_section_ids.get(&section_id).copied());
+            if let Some(offset) = due_date_offset {
+                task_copy.due_at_utc = task.due_at_utc.map(|due_at_utc| due_at_utc + offset);
+            }
+            operation.record_task(None, Some(task_copy));
+        }
+
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(project_copy)
+    }
+
+    /// Creates a project from a template, resolving the relative due dates of
+    /// its tasks against the start date.
+    pub async fn create_project_from_template(
+        &mut self,
+        template: &ProjectTemplate,
+        start_date: DateTime<Utc>,
+    ) -> Result<Project, Box<dyn Error>> {
+        let now = Utc::now();
+        let project = Project {
+            id: Uuid::now_v7(),
+            title: template.title.clone(),
+            emoji: template.emoji.clone(),
+            color: template.color.clone(),
+            description: template.description.clone(),
+            created_at_utc: now,
+            updated_at_utc: now,
+            archived_at_utc: None,
+            is_favorite: false,
+        };
+
+        let mut operation = UndoOperation::new("Create project from template");
+        operation.record_project(None, Some(project.clone()));
+
+        let mut section_ids: HashMap<Uuid, Uuid> = HashMap::new();
+        for template_section in &template.sections {
+            let section = Section::new(
+                project.id,
+                template_section.title.clone(),
+                template_section.position,
+            );
+            section_ids.insert(template_section.key, section.id);
+            operation.record_section(None, Some(section));
+        }
+
+        let task_ids: HashMap<Uuid, Uuid> = template
+            .tasks
+            .iter()
+            .map(|template_task| (template_task.key, Uuid::now_v7()))
+            .collect();
+
+        for template_task in &template.tasks {
+            let mut task = Task::new(
+                template_task.title.clone(),
+                template_task.description.clone(),
+                Some(project.id),
+                template_task
+                    .parent_key
+                    .and_then(|parent_key| task_ids.get(&parent_key).copied()),
+                template_task.due_at_utc(start_date),
+            );
+            task.id = task_ids[&template_task.key];
+            task.section_id = template_task
+                .section_key
+                .and_then(|section_key| section_ids.get(&section_key).copied());
+            task.priority = template_task.priority;
+            task.position = template_task.position.clone();
+            task.auto_complete = template_task.auto_complete;
+            task.estimated_minutes = template_task.estimated_minutes;
+            operation.record_task(None, Some(task));
+        }
+
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(project)
+    }
+
+    /// Turns a project into an inbox task, the former top-level tasks of the
+    /// project becoming its subtasks.
+    pub async fn demote_project_to_task(
+        &mut self,
+        project_id: Uuid,
+    ) -> Result<Task, Box<dyn Error>> {
+        let project = self
+            .project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or("Project not found")?;
+
+        let mut parent_task = Task::new(
+            project.title.clone(),
+            project.description.clone(),
+            None,
+            None,
+            None,
+        );
+        parent_task.position = self.task_repository.position_at_end(&parent_task).await?;
+
+        let mut operation = UndoOperation::new("Convert project to task");
+        operation.record_project(Some(project), None);
+        for section in self.project_repository.find_sections(project_id).await? {
+            operation.record_section(Some(section), None);
+        }
+        operation.record_task(None, Some(parent_task.clone()));
+
+        let mut tasks_before = self.task_repository.find_all_by_project(project_id).await?;
+        // Placed in their current order so they keep it under the new parent
+        tasks_before.sort_by(|a, b| a.position.cmp(&b.position));
+
+        let now = Utc::now();
+        let mut last_position: Option<String> = None;
+        let mut tasks_after = Vec::new();
+        for task in &tasks_before {
+            let mut task = task.clone();
+            task.project_id = None;
+            task.section_id = None;
+            if task.parent_task_id.is_none() {
+                task.parent_task_id = Some(parent_task.id);
+                task.position = position::rank_between(last_position.as_deref(), None)?;
+                last_position = Some(task.position.clone());
+            }
+            task.updated_at_utc = now;
+            tasks_after.push(task);
+        }
+        operation.record_tasks(tasks_before, tasks_after);
+
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(parent_task)
+    }
+
+    /// Turns a task into a project named after it. Its subtasks become the
+    /// top-level tasks of the project and the task itself is archived.
+    pub async fn promote_task_to_project(
+        &mut self,
+        task_id: Uuid,
+    ) -> Result<Project, Box<dyn Error>> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or("Task not found")?;
+
+        let now = Utc::now();
+        let project = Project {
+            id: Uuid::now_v7(),
+            title: task.title.clone(),
+            emoji: None,
+            color: None,
+            description: task.description.clone(),
+            created_at_utc: now,
+            updated_at_utc: now,
+            archived_at_utc: None,
+            is_favorite: false,
+        };
+
+        let mut operation = UndoOperation::new("Convert task to project");
+        operation.record_project(None, Some(project.clone()));
+
+        let mut tasks_before: Vec<Task> = self
+            .task_repository
+            .find_subtree(task_id)
+            .await?
+            .into_iter()
+            .filter(|subtask| subtask.id != task_id)
+            .collect();
+        // Placed in their current order so they keep it in the project
+        tasks_before.sort_by(|a, b| a.position.cmp(&b.position));
+
+        let mut last_position: Option<String> = None;
+        let mut tasks_after = Vec::new();
+        for subtask in &tasks_before {
+            let mut subtask = subtask.clone();
+            subtask.project_id = Some(project.id);
+            subtask.section_id = None;
+            if subtask.parent_task_id == Some(task_id) {
+                subtask.parent_task_id = None;
+                subtask.position = position::rank_between(last_position.as_deref(), None)?;
+                last_position = Some(subtask.position.clone());
+            }
+            subtask.updated_at_utc = now;
+            tasks_after.push(subtask);
+        }
+        operation.record_tasks(tasks_before, tasks_after);
+
+        let mut archived_task = task.clone();
+        archived_task.archived_at_utc = Some(now);
+        archived_task.updated_at_utc = now;
+        operation.record_task(Some(task), Some(archived_task));
+
+        self.undo_repository.commit(&operation).await?;
 
         Ok(project)
     }
@@ -131,11 +665,14 @@ impl<'a> ProjectsManager<'a> {
             .find_by_id(project_id)
             .await?
             .ok_or("Project not found")?;
+        let project_before = project.clone();
 
         project.is_favorite = true;
         project.updated_at_utc = Utc::now();
 
-        self.project_repository.save(&mut project).await?;
+        let mut operation = UndoOperation::new("Add project to favorites");
+        operation.record_project(Some(project_before), Some(project.clone()));
+        self.undo_repository.commit(&operation).await?;
 
         Ok(project)
     }
@@ -147,12 +684,15 @@ impl<'a> ProjectsManager<'a> {
             .await
             .map_err(|e| e.to_string())?
             .ok_or("Project not found")?;
+        let project_before = project.clone();
 
         project.is_favorite = false;
         project.updated_at_utc = Utc::now();
 
-        self.project_repository
-            .save(&mut project)
+        let mut operation = UndoOperation::new("Remove project from favorites");
+        operation.record_project(Some(project_before), Some(project.clone()));
+        self.undo_repository
+            .commit(&operation)
             .await
             .map_err(|e| e.to_string())?;
 
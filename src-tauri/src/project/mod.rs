@@ -21,12 +21,54 @@ pub struct Project {
     pub created_at_utc: DateTime<Utc>,
     pub updated_at_utc: DateTime<Utc>,
     pub archived_at_utc: Option<DateTime<Utc>>,
-    #[serde(rename(serialize = "isFavorite"))]
+    #[serde(rename(serialize = "isFavorite"), alias = "isFavorite")]
     pub is_favorite: bool,
 }
 
+// What happens to the tasks of a project when the project is deleted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum_macros::Display, strum_macros::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum ProjectTaskDisposal {
+    TrashTasks,
+    MoveToInbox,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Section {
+    #[sqlx(try_from = "Hyphenated")]
+    pub id: Uuid,
+    #[sqlx(try_from = "Hyphenated")]
+    pub project_id: Uuid,
+    pub title: String,
+    pub position: i64,
+    pub created_at_utc: DateTime<Utc>,
+    pub updated_at_utc: DateTime<Utc>,
+}
+
+impl Section {
+    pub fn new(project_id: Uuid, title: String, position: i64) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::now_v7(),
+            project_id,
+            title,
+            position,
+            created_at_utc: now,
+            updated_at_utc: now,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SectionDetail {
+    pub section: Section,
+    pub tasks: Vec<Task>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ProjectDetail {
     pub project: Project,
-    pub tasks: Vec<Task>,
+    // Ordered by position
+    pub sections: Vec<SectionDetail>,
+    pub unsectioned_tasks: Vec<Task>,
 }
@@ -1,17 +1,26 @@
 use async_trait::async_trait;
+use chrono::Utc;
 use sqlx::{pool::PoolConnection, Row, Sqlite};
 use uuid::Uuid;
 
-use super::Project;
+use super::{Project, Section};
+use crate::history::{
+    self, repository::insert_events, HistoryEntityType, HistoryEvent, HistoryField,
+};
 
 #[async_trait]
 pub trait ProjectRepository: Send + Sync {
     async fn save(&mut self, project: &mut Project) -> Result<(), sqlx::Error>;
+    async fn delete(&mut self, project: &Project) -> Result<(), sqlx::Error>;
     async fn find_by_id(&mut self, id: Uuid) -> Result<Option<Project>, sqlx::Error>;
     async fn find_not_archived(&mut self) -> Result<Vec<Project>, sqlx::Error>;
     async fn find_all(&mut self) -> Result<Vec<Project>, sqlx::Error>;
     async fn find_favorites(&mut self) -> Result<Vec<Project>, sqlx::Error>;
     async fn count_open_tasks(&mut self, project_id: Uuid) -> Result<i64, sqlx::Error>;
+    async fn save_section(&mut self, section: &mut Section) -> Result<(), sqlx::Error>;
+    async fn delete_section(&mut self, section: &Section) -> Result<(), sqlx::Error>;
+    async fn find_section_by_id(&mut self, id: Uuid) -> Result<Option<Section>, sqlx::Error>;
+    async fn find_sections(&mut self, project_id: Uuid) -> Result<Vec<Section>, sqlx::Error>;
 }
 
 pub struct SqliteProjectRepository {
@@ -27,13 +36,9 @@ impl SqliteProjectRepository {
 #[async_trait]
 impl ProjectRepository for SqliteProjectRepository {
     async fn save(&mut self, project: &mut Project) -> Result<(), sqlx::Error> {
-        let exists = sqlx::query("SELECT 1 FROM projects WHERE id = ?1 LIMIT 1")
-            .bind(project.id.to_string())
-            .fetch_optional(&mut *self.connection)
-            .await?
-            .is_some();
+        let previous = self.find_by_id(project.id).await?;
 
-        if exists {
+        if previous.is_some() {
             sqlx::query(
                 "UPDATE projects SET title = ?1, emoji = ?2, color = ?3, description = ?4, updated_at_utc = ?5, archived_at_utc = ?6, is_favorite = ?7 WHERE id = ?8"
             )
@@ -63,6 +68,32 @@ impl ProjectRepository for SqliteProjectRepository {
             .await?;
         }
 
+        let events = history::project_changes(previous.as_ref(), project);
+        insert_events(&mut self.connection, &events).await?;
+
+        Ok(())
+    }
+
+    async fn delete(&mut self, project: &Project) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM sections WHERE project_id = ?1")
+            .bind(project.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        sqlx::query("DELETE FROM projects WHERE id = ?1")
+            .bind(project.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        let event = HistoryEvent::new(
+            HistoryEntityType::Project,
+            project.id,
+            HistoryField::DeletedAtUtc,
+            None,
+            Some(Utc::now().to_rfc3339()),
+        );
+        insert_events(&mut self.connection, &[event]).await?;
+
         Ok(())
     }
 
@@ -93,7 +124,7 @@ impl ProjectRepository for SqliteProjectRepository {
 
     async fn count_open_tasks(&mut self, project_id: Uuid) -> Result<i64, sqlx::Error> {
         let result = sqlx::query(
-            "SELECT COUNT(*) as count FROM tasks WHERE project_id = ?1 AND completed_at_utc IS NULL"
+            "SELECT COUNT(*) as count FROM tasks WHERE project_id = ?1 AND completed_at_utc IS NULL AND deleted_at_utc IS NULL AND archived_at_utc IS NULL"
         )
         .bind(project_id.to_string())
         .fetch_one(&mut *self.connection)
@@ -101,4 +132,65 @@ impl ProjectRepository for SqliteProjectRepository {
 
         Ok(result.get("count"))
     }
+
+    async fn save_section(&mut self, section: &mut Section) -> Result<(), sqlx::Error> {
+        let exists = sqlx::query("SELECT 1 FROM sections WHERE id = ?1 LIMIT 1")
+            .bind(section.id.to_string())
+            .fetch_optional(&mut *self.connection)
+            .await?
+            .is_some();
+
+        section.updated_at_utc = Utc::now();
+
+        if exists {
+            sqlx::query(
+                "UPDATE sections SET title = ?1, position = ?2, updated_at_utc = ?3 WHERE id = ?4",
+            )
+            .bind(&section.title)
+            .bind(section.position)
+            .bind(section.updated_at_utc.to_rfc3339())
+            .bind(section.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+        } else {
+            sqlx::query(
+                "INSERT INTO sections (id, project_id, title, position, created_at_utc, updated_at_utc) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )
+            .bind(section.id.to_string())
+            .bind(section.project_id.to_string())
+            .bind(&section.title)
+            .bind(section.position)
+            .bind(section.created_at_utc.to_rfc3339())
+            .bind(section.updated_at_utc.to_rfc3339())
+            .execute(&mut *self.connection)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete_section(&mut self, section: &Section) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM sections WHERE id = ?1")
+            .bind(section.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_section_by_id(&mut self, id: Uuid) -> Result<Option<Section>, sqlx::Error> {
+        sqlx::query_as::<_, Section>("SELECT * FROM sections WHERE id = ?1 LIMIT 1")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.connection)
+            .await
+    }
+
+    async fn find_sections(&mut self, project_id: Uuid) -> Result<Vec<Section>, sqlx::Error> {
+        sqlx::query_as::<_, Section>(
+            "SELECT * FROM sections WHERE project_id = ?1 ORDER BY position ASC, created_at_utc ASC",
+        )
+        .bind(project_id.to_string())
+        .fetch_all(&mut *self.connection)
+        .await
+    }
 }
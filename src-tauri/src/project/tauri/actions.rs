@@ -1,9 +1,10 @@
+use chrono::Duration;
 use tauri::State;
 use uuid::Uuid;
 
 use crate::errors::handle_error;
 use crate::project::manager::ProjectsManager;
-use crate::project::Project;
+use crate::project::{Project, ProjectTaskDisposal};
 use crate::repository::RepositoryProvider;
 
 #[tauri::command]
@@ -24,7 +25,16 @@ pub async fn create_project_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let project = projects_manager
         .create_project(title, emoji, color, description)
@@ -54,7 +64,16 @@ pub async fn update_project_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     projects_manager
         .update_project(project_uuid, title, emoji, color, description)
@@ -78,10 +97,385 @@ pub async fn archive_project_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     projects_manager
         .archive_project(project_uuid)
         .await
         .map_err(|e| handle_error(&*e))
 }
+
+#[tauri::command]
+pub async fn unarchive_project_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    project_id: String,
+) -> Result<Project, String> {
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+
+    projects_manager
+        .unarchive_project(project_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn delete_project_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    project_id: String,
+    task_disposal: String,
+) -> Result<String, String> {
+    log::debug!(
+        "Running delete project command for project ID: {} | {}",
+        project_id,
+        task_disposal
+    );
+
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    let task_disposal: ProjectTaskDisposal = task_disposal.parse().map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+
+    projects_manager
+        .delete_project(project_uuid, task_disposal)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(format!(
+        "Project with ID {} deleted successfully",
+        &project_id
+    ))
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn duplicate_project_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    project_id: String,
+    due_date_offset_days: Option<i64>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running duplicate project command for: {} | {:?}",
+        project_id,
+        due_date_offset_days
+    );
+
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+
+    let project = projects_manager
+        .duplicate_project(project_uuid, due_date_offset_days.map(Duration::days))
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&project).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn demote_project_to_task_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    project_id: String,
+) -> Result<String, String> {
+    log::debug!("Running demote project to task command for: {}", project_id);
+
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+
+    let task = projects_manager
+        .demote_project_to_task(project_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&task).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn create_section_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    project_id: String,
+    title: String,
+) -> Result<String, String> {
+    log::debug!(
+        "Running create section command for: {} | {}",
+        project_id,
+        title
+    );
+
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+    let section = projects_manager
+        .create_section(project_uuid, title)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&section).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn rename_section_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    section_id: String,
+    title: String,
+) -> Result<String, String> {
+    log::debug!(
+        "Running rename section command for: {} | {}",
+        section_id,
+        title
+    );
+
+    let section_uuid = Uuid::parse_str(&section_id).map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+    let section = projects_manager
+        .rename_section(section_uuid, title)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&section).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn delete_section_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    section_id: String,
+) -> Result<String, String> {
+    log::debug!("Running delete section command for: {}", section_id);
+
+    let section_uuid = Uuid::parse_str(&section_id).map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+    projects_manager
+        .delete_section(section_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(format!(
+        "Section with ID {} deleted successfully",
+        &section_id
+    ))
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn reorder_sections_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    project_id: String,
+    section_ids: Vec<String>,
+) -> Result<String, String> {
+    log::debug!("Running reorder sections command for: {}", project_id);
+
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
+    let section_uuids = section_ids
+        .iter()
+        .map(|section_id| Uuid::parse_str(section_id))
+        .collect::<Result<Vec<Uuid>, _>>()
+        .map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+    let sections = projects_manager
+        .reorder_sections(project_uuid, section_uuids)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&sections).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn move_task_to_section_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    task_id: String,
+    section_id: Option<String>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running move task to section command for: {} | {:?}",
+        task_id,
+        section_id
+    );
+
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let section_uuid = section_id
+        .map(|section_id| Uuid::parse_str(&section_id))
+        .transpose()
+        .map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+    let task = projects_manager
+        .move_task_to_section(task_uuid, section_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&task).unwrap())
+}
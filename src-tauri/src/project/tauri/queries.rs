@@ -21,7 +21,16 @@ pub async fn load_favorite_projects_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let projects = projects_manager
         .load_favorites()
@@ -46,7 +55,16 @@ pub async fn load_projects_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let projects = projects_manager
         .load_all(show_archived_projects)
@@ -72,7 +90,16 @@ pub async fn load_project_details_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
 
@@ -99,7 +126,16 @@ pub async fn count_open_tasks_for_project_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
 
@@ -126,7 +162,16 @@ pub async fn add_favorite_project_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
 
@@ -153,7 +198,16 @@ pub async fn remove_favorite_project_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
 
@@ -164,3 +218,39 @@ pub async fn remove_favorite_project_command(
 
     Ok(serde_json::to_string(&project).unwrap())
 }
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn load_sections_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    project_id: String,
+) -> Result<String, String> {
+    log::debug!("Running load sections command for: {}", project_id);
+
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .inner()
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .inner()
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+    let sections = projects_manager
+        .load_sections(project_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&sections).unwrap())
+}
@@ -1,12 +1,17 @@
 #[cfg(test)]
 mod manager_test {
     use crate::project::manager::ProjectsManager;
+    use crate::project::ProjectTaskDisposal;
     use crate::repository::RepositoryProvider;
+    use crate::task::manager::TaskManager;
+    use crate::task::CreateTaskData;
+    use crate::undo::manager::UndoManager;
 
-    use chrono::Utc;
+    use chrono::{Duration, Utc};
     use sqlx::migrate::MigrateDatabase;
     use sqlx::sqlite::SqlitePool;
     use sqlx::Sqlite;
+    use uuid::Uuid;
 
     async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
         let url = format!("sqlite://{}", ":memory:");
@@ -23,6 +28,26 @@ mod manager_test {
         Ok(RepositoryProvider::new(pool))
     }
 
+    fn task_data(title: &str, project_id: Uuid) -> CreateTaskData {
+        CreateTaskData {
+            title: title.to_string(),
+            description: None,
+            project_id: Some(project_id.to_string()),
+            due_at_utc: None,
+            priority: None,
+        }
+    }
+
+    fn task_data_without_project(title: &str) -> CreateTaskData {
+        CreateTaskData {
+            title: title.to_string(),
+            description: None,
+            project_id: None,
+            due_at_utc: None,
+            priority: None,
+        }
+    }
+
     #[tokio::test]
     async fn it_creates_a_project() {
         let title = String::from("Test Project");
@@ -30,8 +55,12 @@ mod manager_test {
         let provider = setup_test_db().await.unwrap();
         let mut project_repository = provider.project_repository().await.unwrap();
         let mut task_repository = provider.task_repository().await.unwrap();
-        let mut project_manager =
-            ProjectsManager::new(&mut project_repository, &mut task_repository);
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
 
         let project = project_manager
             .create_project(title, None, None, None)
@@ -53,8 +82,12 @@ mod manager_test {
         let provider = setup_test_db().await.unwrap();
         let mut project_repository = provider.project_repository().await.unwrap();
         let mut task_repository = provider.task_repository().await.unwrap();
-        let mut project_manager =
-            ProjectsManager::new(&mut project_repository, &mut task_repository);
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
 
         let project = project_manager
             .create_project("Test Project".to_string(), None, None, None)
@@ -89,8 +122,12 @@ mod manager_test {
         let provider = setup_test_db().await.unwrap();
         let mut project_repository = provider.project_repository().await.unwrap();
         let mut task_repository = provider.task_repository().await.unwrap();
-        let mut project_manager =
-            ProjectsManager::new(&mut project_repository, &mut task_repository);
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
 
         let project = project_manager
             .create_project("Test Project".to_string(), None, None, None)
@@ -107,8 +144,12 @@ mod manager_test {
         let provider = setup_test_db().await.unwrap();
         let mut project_repository = provider.project_repository().await.unwrap();
         let mut task_repository = provider.task_repository().await.unwrap();
-        let mut project_manager =
-            ProjectsManager::new(&mut project_repository, &mut task_repository);
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
 
         let project = project_manager
             .create_project("Test Project".to_string(), None, None, None)
@@ -130,4 +171,475 @@ mod manager_test {
         assert_eq!(favorite_project.id, unfavorited_project.id);
         assert!(!unfavorited_project.is_favorite);
     }
+
+    #[tokio::test]
+    async fn it_unarchives_a_project() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let project = project_manager
+            .create_project("Test Project".to_string(), None, None, None)
+            .await
+            .unwrap();
+
+        assert!(project_manager.unarchive_project(project.id).await.is_err());
+
+        project_manager.archive_project(project.id).await.unwrap();
+        let unarchived_project = project_manager.unarchive_project(project.id).await.unwrap();
+
+        assert!(unarchived_project.archived_at_utc.is_none());
+        assert_eq!(1, project_manager.load_all(false).await.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn deleting_a_project_can_move_its_tasks_to_the_inbox() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let project = project_manager
+            .create_project("Test Project".to_string(), None, None, None)
+            .await
+            .unwrap();
+
+        let section = project_manager
+            .create_section(project.id, "Backlog".to_string())
+            .await
+            .unwrap();
+
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(task_data("Project Task", project.id))
+            .await
+            .unwrap();
+        project_manager
+            .move_task_to_section(task.id, Some(section.id))
+            .await
+            .unwrap();
+
+        project_manager
+            .delete_project(project.id, ProjectTaskDisposal::MoveToInbox)
+            .await
+            .unwrap();
+
+        assert!(project_manager.load_all(true).await.unwrap().is_empty());
+
+        let inbox = task_manager.load_inbox().await.unwrap();
+        assert_eq!(1, inbox.len());
+        assert_eq!(task.id, inbox[0].id);
+
+        // Undoing brings the project back with its sections
+        UndoManager::new(&provider).undo().await.unwrap().unwrap();
+        let detail = project_manager
+            .load_project_detail(project.id, false)
+            .await
+            .unwrap();
+        assert_eq!(1, detail.sections.len());
+        assert_eq!(section.id, detail.sections[0].section.id);
+        assert_eq!(1, detail.sections[0].tasks.len());
+        assert_eq!(task.id, detail.sections[0].tasks[0].id);
+    }
+
+    #[tokio::test]
+    async fn deleting_a_project_can_move_its_tasks_to_the_trash() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let project = project_manager
+            .create_project("Test Project".to_string(), None, None, None)
+            .await
+            .unwrap();
+
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(task_data("Project Task", project.id))
+            .await
+            .unwrap();
+
+        project_manager
+            .delete_project(project.id, ProjectTaskDisposal::TrashTasks)
+            .await
+            .unwrap();
+
+        assert!(task_manager.load_inbox().await.unwrap().is_empty());
+
+        let trash = task_manager.load_trash().await.unwrap();
+        assert_eq!(1, trash.len());
+        assert_eq!(task.id, trash[0].id);
+
+        // A single undo brings back the project with its task
+        UndoManager::new(&provider).undo().await.unwrap();
+        let restored_task = task_manager.load_task(task.id).await.unwrap();
+        assert_eq!(Some(project.id), restored_task.project_id);
+        assert!(restored_task.deleted_at_utc.is_none());
+        UndoManager::new(&provider).redo().await.unwrap();
+
+        // Restored tasks land in the inbox since their project is gone
+        let restored_task = task_manager.restore_task(task.id).await.unwrap();
+        assert!(restored_task.project_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn it_groups_project_tasks_by_section() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let project = project_manager
+            .create_project("Test Project".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let backlog = project_manager
+            .create_section(project.id, "Backlog".to_string())
+            .await
+            .unwrap();
+        let doing = project_manager
+            .create_section(project.id, "Doing".to_string())
+            .await
+            .unwrap();
+
+        let task_manager = TaskManager::new(&provider);
+        let sectioned_task = task_manager
+            .create_task(task_data("Sectioned Task", project.id))
+            .await
+            .unwrap();
+        let unsectioned_task = task_manager
+            .create_task(task_data("Unsectioned Task", project.id))
+            .await
+            .unwrap();
+        project_manager
+            .move_task_to_section(sectioned_task.id, Some(doing.id))
+            .await
+            .unwrap();
+
+        project_manager
+            .reorder_sections(project.id, vec![doing.id, backlog.id])
+            .await
+            .unwrap();
+        let undo_manager = UndoManager::new(&provider);
+        undo_manager.undo().await.unwrap().unwrap();
+        undo_manager.redo().await.unwrap().unwrap();
+
+        let detail = project_manager
+            .load_project_detail(project.id, false)
+            .await
+            .unwrap();
+
+        assert_eq!(2, detail.sections.len());
+        assert_eq!(doing.id, detail.sections[0].section.id);
+        assert_eq!(1, detail.sections[0].tasks.len());
+        assert_eq!(sectioned_task.id, detail.sections[0].tasks[0].id);
+        assert!(detail.sections[1].tasks.is_empty());
+        assert_eq!(1, detail.unsectioned_tasks.len());
+        assert_eq!(unsectioned_task.id, detail.unsectioned_tasks[0].id);
+    }
+
+    #[tokio::test]
+    async fn deleting_a_section_keeps_its_tasks_in_the_project() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let project = project_manager
+            .create_project("Test Project".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let other_project = project_manager
+            .create_project("Other Project".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let section = project_manager
+            .create_section(project.id, "Backlog".to_string())
+            .await
+            .unwrap();
+
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(task_data("Project Task", project.id))
+            .await
+            .unwrap();
+        let other_task = task_manager
+            .create_task(task_data("Other Task", other_project.id))
+            .await
+            .unwrap();
+
+        // Tasks can only be moved to sections of their own project
+        assert!(project_manager
+            .move_task_to_section(other_task.id, Some(section.id))
+            .await
+            .is_err());
+
+        project_manager
+            .move_task_to_section(task.id, Some(section.id))
+            .await
+            .unwrap();
+        project_manager.delete_section(section.id).await.unwrap();
+
+        let detail = project_manager
+            .load_project_detail(project.id, false)
+            .await
+            .unwrap();
+        assert!(detail.sections.is_empty());
+        assert_eq!(1, detail.unsectioned_tasks.len());
+        assert!(detail.unsectioned_tasks[0].section_id.is_none());
+
+        // Undoing brings the section back with its tasks
+        UndoManager::new(&provider).undo().await.unwrap().unwrap();
+        let detail = project_manager
+            .load_project_detail(project.id, false)
+            .await
+            .unwrap();
+        assert_eq!(1, detail.sections.len());
+        assert_eq!(task.id, detail.sections[0].tasks[0].id);
+        assert!(detail.unsectioned_tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn demoting_a_project_turns_its_tasks_into_subtasks() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let project = project_manager
+            .create_project(
+                "Test Project".to_string(),
+                None,
+                None,
+                Some("Project description".to_string()),
+            )
+            .await
+            .unwrap();
+
+        let task_manager = TaskManager::new(&provider);
+        let first = task_manager
+            .create_task(task_data("First Task", project.id))
+            .await
+            .unwrap();
+        let second = task_manager
+            .create_task(task_data("Second Task", project.id))
+            .await
+            .unwrap();
+        let section = project_manager
+            .create_section(project.id, "Backlog".to_string())
+            .await
+            .unwrap();
+        project_manager
+            .move_task_to_section(first.id, Some(section.id))
+            .await
+            .unwrap();
+
+        let parent_task = project_manager
+            .demote_project_to_task(project.id)
+            .await
+            .unwrap();
+
+        assert_eq!("Test Project", parent_task.title);
+        assert_eq!(
+            Some("Project description".to_string()),
+            parent_task.description
+        );
+        assert!(project_manager.load_all(true).await.unwrap().is_empty());
+
+        let subtasks = task_manager
+            .load_subtasks_for_task(parent_task.id)
+            .await
+            .unwrap();
+        let subtask_ids: Vec<Uuid> = subtasks.iter().map(|task| task.id).collect();
+        assert_eq!(vec![first.id, second.id], subtask_ids);
+        assert!(subtasks.iter().all(|task| task.project_id.is_none()));
+
+        let inbox = task_manager.load_inbox().await.unwrap();
+        assert!(inbox.iter().any(|task| task.id == parent_task.id));
+
+        // Undoing restores the project with its sections and removes the parent task
+        UndoManager::new(&provider).undo().await.unwrap().unwrap();
+        let detail = project_manager
+            .load_project_detail(project.id, false)
+            .await
+            .unwrap();
+        assert_eq!(1, detail.sections.len());
+        assert_eq!(section.id, detail.sections[0].section.id);
+        assert_eq!(first.id, detail.sections[0].tasks[0].id);
+        assert_eq!(1, detail.unsectioned_tasks.len());
+        assert!(task_manager
+            .load_by_id(parent_task.id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn promoting_a_task_turns_its_subtree_into_a_project() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(CreateTaskData {
+                title: "Fix fence".to_string(),
+                description: Some("Before winter".to_string()),
+                project_id: None,
+                due_at_utc: None,
+                priority: None,
+            })
+            .await
+            .unwrap();
+        let task_id = task.id;
+        let child = task_manager
+            .create_subtask_for_task(task.clone(), task_data_without_project("Buy supplies"))
+            .await
+            .unwrap();
+        let second_child = task_manager
+            .create_subtask_for_task(task, task_data_without_project("Paint"))
+            .await
+            .unwrap();
+        let grandchild = task_manager
+            .create_subtask_for_task(child.clone(), task_data_without_project("Buy nails"))
+            .await
+            .unwrap();
+
+        let project = project_manager
+            .promote_task_to_project(task_id)
+            .await
+            .unwrap();
+
+        assert_eq!("Fix fence", project.title);
+        assert_eq!(Some("Before winter".to_string()), project.description);
+        let detail = project_manager
+            .load_project_detail(project.id, false)
+            .await
+            .unwrap();
+        let top_level_ids: Vec<Uuid> = detail
+            .unsectioned_tasks
+            .iter()
+            .filter(|task| task.parent_task_id.is_none())
+            .map(|task| task.id)
+            .collect();
+        assert_eq!(vec![child.id, second_child.id], top_level_ids);
+        let grandchild = task_manager.load_task(grandchild.id).await.unwrap();
+        assert_eq!(Some(project.id), grandchild.project_id);
+        assert_eq!(Some(child.id), grandchild.parent_task_id);
+        let task = task_manager.load_task(task_id).await.unwrap();
+        assert!(task.archived_at_utc.is_some());
+
+        // A single undo reverts the whole promotion
+        UndoManager::new(&provider).undo().await.unwrap().unwrap();
+        assert!(project_manager.load_all(true).await.unwrap().is_empty());
+        let task = task_manager.load_task(task_id).await.unwrap();
+        assert!(task.archived_at_utc.is_none());
+        let subtasks = task_manager.load_subtasks_for_task(task_id).await.unwrap();
+        let subtask_ids: Vec<Uuid> = subtasks.iter().map(|task| task.id).collect();
+        assert_eq!(vec![child.id, second_child.id], subtask_ids);
+        let grandchild = task_manager.load_task(grandchild.id).await.unwrap();
+        assert!(grandchild.project_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn duplicating_a_project_copies_its_open_tasks() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut project_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let project = project_manager
+            .create_project("Launch".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let section = project_manager
+            .create_section(project.id, "Marketing".to_string())
+            .await
+            .unwrap();
+
+        let task_manager = TaskManager::new(&provider);
+        let due_date = Utc::now();
+        let open_task = task_manager
+            .create_task(CreateTaskData {
+                due_at_utc: Some(due_date.to_rfc3339()),
+                ..task_data("Write announcement", project.id)
+            })
+            .await
+            .unwrap();
+        project_manager
+            .move_task_to_section(open_task.id, Some(section.id))
+            .await
+            .unwrap();
+        let done_task = task_manager
+            .create_task(task_data("Pick a date", project.id))
+            .await
+            .unwrap();
+        task_manager.complete_task(done_task.id).await.unwrap();
+
+        let copy = project_manager
+            .duplicate_project(project.id, Some(Duration::days(7)))
+            .await
+            .unwrap();
+        assert_ne!(project.id, copy.id);
+        assert_eq!("Launch", copy.title);
+
+        let detail = project_manager
+            .load_project_detail(copy.id, true)
+            .await
+            .unwrap();
+        assert_eq!(1, detail.sections.len());
+        assert_eq!("Marketing", detail.sections[0].section.title);
+        assert_eq!(1, detail.sections[0].tasks.len());
+        assert!(detail.unsectioned_tasks.is_empty());
+
+        let task_copy = &detail.sections[0].tasks[0];
+        assert_eq!("Write announcement", task_copy.title);
+        assert_eq!(
+            (due_date + Duration::days(7)).timestamp(),
+            task_copy.due_at_utc.unwrap().timestamp()
+        );
+    }
 }
@@ -1,25 +1,33 @@
-use chrono::{DateTime, Datelike, Utc};
+use chrono::{DateTime, Months, Utc};
 use uuid::Uuid;
 
 use crate::task::repository::TaskRepository;
 use crate::task::Task;
+use crate::undo::repository::UndoRepository;
+use crate::undo::UndoOperation;
 
 use super::repository::RecurringTaskRepository;
-use super::{Frequency, RecurringTask};
+use super::rule::RecurrenceRule;
+use super::{Frequency, RecurrenceAnchor, RecurringTask};
+
+const OUT_OF_RANGE: &str = "The next occurrence is out of range";
 
 pub struct RecurringTaskManager<'a> {
     recurring_task_repository: &'a mut dyn RecurringTaskRepository,
     task_repository: &'a mut dyn TaskRepository,
+    undo_repository: &'a mut dyn UndoRepository,
 }
 
 impl<'a> RecurringTaskManager<'a> {
     pub fn new(
         recurring_task_repository: &'a mut dyn RecurringTaskRepository,
         task_repository: &'a mut dyn TaskRepository,
+        undo_repository: &'a mut dyn UndoRepository,
     ) -> Self {
         Self {
             recurring_task_repository,
             task_repository,
+            undo_repository,
         }
     }
 
@@ -28,6 +36,24 @@ impl<'a> RecurringTaskManager<'a> {
         task_id: Uuid,
         frequency: Frequency,
         interval: i32,
+    ) -> Result<RecurringTask, Box<dyn std::error::Error>> {
+        self.setup_recurring_task_with_rule(
+            task_id,
+            RecurrenceRule::new(frequency, interval),
+            RecurrenceAnchor::Scheduled,
+            None,
+            None,
+        )
+        .await
+    }
+
+    pub async fn setup_recurring_task_with_rule(
+        &mut self,
+        task_id: Uuid,
+        rule: RecurrenceRule,
+        anchor: RecurrenceAnchor,
+        until_utc: Option<DateTime<Utc>>,
+        remaining_occurrences: Option<i32>,
     ) -> Result<RecurringTask, Box<dyn std::error::Error>> {
         let task = self.task_repository.find_by_id(task_id).await?;
         let task = task.ok_or("Task not found")?;
@@ -42,13 +68,19 @@ impl<'a> RecurringTaskManager<'a> {
         }
 
         let base_date = task.due_at_utc.unwrap_or_else(Utc::now);
-        let next_due_at_utc =
-            self.calculate_due_date_for_base_date_and_frequency(base_date, &frequency, interval)?;
+        let next_due_at_utc = Self::calculate_next_due_date(base_date, &rule)?
+            .ok_or("The recurrence has no occurrence after the task's due date")?;
+
+        let mut recurring_task =
+            RecurringTask::new(task_id, rule.frequency, rule.interval, next_due_at_utc);
+        recurring_task.set_recurrence_rule(&rule);
+        recurring_task.anchor = anchor.to_string();
+        Self::set_end_conditions(&mut recurring_task, until_utc, remaining_occurrences)?;
+
+        let mut operation = UndoOperation::new("Set up recurring task");
+        operation.record_recurring_task(None, Some(recurring_task.clone()));
+        self.undo_repository.commit(&operation).await?;
 
-        let mut recurring_task = RecurringTask::new(task_id, frequency, interval, next_due_at_utc);
-        self.recurring_task_repository
-            .save(&mut recurring_task)
-            .await?;
         Ok(recurring_task)
     }
 
@@ -56,76 +88,185 @@ impl<'a> RecurringTaskManager<'a> {
         &mut self,
         task_id: Uuid,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        self.recurring_task_repository
-            .delete_by_task_id(task_id)
+        let recurring_task = self
+            .recurring_task_repository
+            .find_by_task_id(task_id)
             .await?;
+
+        let mut operation = UndoOperation::new("Delete recurring task");
+        operation.record_recurring_task(recurring_task, None);
+        if !operation.changes.is_empty() {
+            self.undo_repository.commit(&operation).await?;
+        }
+
         Ok(())
     }
 
-    pub async fn handle_task_completion(
-        &mut self,
+    // Works out the task for the occurrence following the completed `task` and the recurrence
+    // moved on to it, without writing either. The recurrence is `None` once it has no
+    // occurrence left, and so is the task when the completed one was the last occurrence
+    pub fn next_occurrence(
         task: &Task,
-    ) -> Result<Option<Task>, Box<dyn std::error::Error>> {
-        // Check if this task has a recurring configuration
-        if let Some(mut recurring_task) = self
-            .recurring_task_repository
-            .find_by_task_id(task.id)
-            .await?
-        {
-            // Create a new task for the next occurrence
-            let mut new_task = Task::new(
-                task.title.clone(),
-                task.description.clone(),
-                task.project_id,
-                task.parent_task_id,
-                Some(recurring_task.next_due_at_utc),
-            );
+        mut recurring_task: RecurringTask,
+    ) -> Result<(Option<Task>, Option<RecurringTask>), Box<dyn std::error::Error>> {
+        let rule = recurring_task.recurrence_rule()?;
 
-            // Calculate the next due date based on frequency and interval
-            let frequency = recurring_task.frequency()?;
-            let next_due_date = self.calculate_due_date_for_base_date_and_frequency(
-                recurring_task.next_due_at_utc,
-                &frequency,
-                recurring_task.interval,
-            )?;
+        // Completion anchored recurrences restart from the day the task was completed,
+        // keeping the time of day of the series
+        let new_due_date = match recurring_task.anchor()? {
+            RecurrenceAnchor::Scheduled => Some(recurring_task.next_due_at_utc),
+            RecurrenceAnchor::Completion => {
+                let completed_at = task.completed_at_utc.unwrap_or_else(Utc::now);
+                let base_date = DateTime::from_naive_utc_and_offset(
+                    completed_at
+                        .date_naive()
+                        .and_time(recurring_task.next_due_at_utc.time()),
+                    Utc,
+                );
+                Self::calculate_next_due_date(base_date, &rule)?
+            }
+        };
 
-            // Save the new task
-            self.task_repository.save(&mut new_task).await?;
+        // Stop generating occurrences once the recurrence's end conditions are exhausted
+        let Some(new_due_date) =
+            new_due_date.filter(|new_due_date| recurring_task.has_occurrence_at(*new_due_date))
+        else {
+            return Ok((None, None));
+        };
 
-            // Update the recurring task with the new task_id and next_due_date
+        // Create a new task for the next occurrence
+        let mut new_task = Task::new(
+            task.title.clone(),
+            task.description.clone(),
+            task.project_id,
+            task.parent_task_id,
+            Some(new_due_date),
+        );
+        new_task.priority = task.priority;
+        new_task.auto_complete = task.auto_complete;
+
+        if let Some(remaining_occurrences) = recurring_task.remaining_occurrences.as_mut() {
+            *remaining_occurrences -= 1;
+        }
+
+        // Calculate the next due date based on the recurrence rule
+        let next_due_date = Self::calculate_next_due_date(new_due_date, &rule)?
+            .filter(|next_due_date| recurring_task.has_occurrence_at(*next_due_date));
+
+        // The recurrence follows the new task, which is the last occurrence when the
+        // recurrence has no date after it
+        let recurring_task = next_due_date.map(|next_due_date| {
             recurring_task.task_id = new_task.id;
             recurring_task.next_due_at_utc = next_due_date;
-            self.recurring_task_repository
-                .save(&mut recurring_task)
-                .await?;
+            recurring_task.updated_at_utc = Utc::now();
+            recurring_task
+        });
+
+        Ok((Some(new_task), recurring_task))
+    }
 
-            Ok(Some(new_task))
-        } else {
-            Ok(None)
+    // Moves the task to the recurrence's next occurrence without completing it
+    pub async fn skip_occurrence(
+        &mut self,
+        task_id: Uuid,
+    ) -> Result<Task, Box<dyn std::error::Error>> {
+        let mut recurring_task = self
+            .recurring_task_repository
+            .find_by_task_id(task_id)
+            .await?
+            .ok_or("No recurring task found for this task")?;
+        let mut task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or("Task not found")?;
+        if task.completed_at_utc.is_some() {
+            return Err("A completed occurrence cannot be skipped".into());
+        }
+
+        let recurring_task_before = recurring_task.clone();
+        let task_before = task.clone();
+
+        let now = Utc::now();
+        task.due_at_utc = Some(recurring_task.next_due_at_utc);
+        task.updated_at_utc = now;
+
+        if let Some(remaining_occurrences) = recurring_task.remaining_occurrences.as_mut() {
+            *remaining_occurrences -= 1;
         }
+        let next_due_date = Self::calculate_next_due_date(
+            recurring_task.next_due_at_utc,
+            &recurring_task.recurrence_rule()?,
+        )?
+        .filter(|next_due_date| recurring_task.has_occurrence_at(*next_due_date));
+
+        // The task becomes the last occurrence when the recurrence has no date after it
+        let recurring_task_after = next_due_date.map(|next_due_date| {
+            recurring_task.next_due_at_utc = next_due_date;
+            recurring_task.updated_at_utc = now;
+            recurring_task
+        });
+
+        let mut operation = UndoOperation::new("Skip occurrence");
+        operation.record_task(Some(task_before), Some(task.clone()));
+        operation.record_recurring_task(Some(recurring_task_before), recurring_task_after);
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(task)
     }
 
-    pub async fn handle_task_update(
+    // Moves only the task's due date, unlike updating the task's due date the rest of the
+    // series keeps its dates
+    pub async fn postpone_occurrence(
         &mut self,
         task_id: Uuid,
         new_due_date: DateTime<Utc>,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        // Check if this task has a recurring configuration
-        if let Some(mut recurring_task) = self
+    ) -> Result<Task, Box<dyn std::error::Error>> {
+        let recurring_task = self
             .recurring_task_repository
             .find_by_task_id(task_id)
             .await?
-        {
-            recurring_task.next_due_at_utc = self.calculate_due_date_for_base_date_and_frequency(
-                new_due_date,
-                &recurring_task.frequency()?,
-                recurring_task.interval,
-            )?;
-            self.recurring_task_repository
-                .save(&mut recurring_task)
-                .await?;
+            .ok_or("No recurring task found for this task")?;
+        let mut task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or("Task not found")?;
+
+        if new_due_date >= recurring_task.next_due_at_utc {
+            return Err(
+                "An occurrence cannot be postponed past the next one, skip it instead".into(),
+            );
         }
-        Ok(())
+
+        let task_before = task.clone();
+        task.due_at_utc = Some(new_due_date);
+        task.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Postpone occurrence");
+        operation.record_task(Some(task_before), Some(task.clone()));
+        self.undo_repository.commit(&operation).await?;
+
+        Ok(task)
+    }
+
+    // Moving a task's due date moves the series along with it. This is the recurrence once
+    // its task is moved to `new_due_date`, without writing it, `None` when the series has no
+    // occurrence after the new date. For completion anchored recurrences the next due date
+    // is only a projection until the task gets completed
+    pub fn rescheduled(
+        mut recurring_task: RecurringTask,
+        new_due_date: DateTime<Utc>,
+    ) -> Result<Option<RecurringTask>, Box<dyn std::error::Error>> {
+        let next_due_date =
+            Self::calculate_next_due_date(new_due_date, &recurring_task.recurrence_rule()?)?
+                .filter(|next_due_date| recurring_task.has_occurrence_at(*next_due_date));
+
+        Ok(next_due_date.map(|next_due_date| {
+            recurring_task.next_due_at_utc = next_due_date;
+            recurring_task.updated_at_utc = Utc::now();
+            recurring_task
+        }))
     }
 
     pub async fn update_recurring_task(
@@ -133,59 +274,135 @@ impl<'a> RecurringTaskManager<'a> {
         task_id: Uuid,
         frequency: Frequency,
         interval: i32,
+    ) -> Result<RecurringTask, Box<dyn std::error::Error>> {
+        self.update_recurring_task_with_rule(
+            task_id,
+            RecurrenceRule::new(frequency, interval),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    // Leaves the anchor unchanged when none is given, the end conditions are always replaced
+    pub async fn update_recurring_task_with_rule(
+        &mut self,
+        task_id: Uuid,
+        rule: RecurrenceRule,
+        anchor: Option<RecurrenceAnchor>,
+        until_utc: Option<DateTime<Utc>>,
+        remaining_occurrences: Option<i32>,
     ) -> Result<RecurringTask, Box<dyn std::error::Error>> {
         let mut recurring_task = self
             .recurring_task_repository
             .find_by_task_id(task_id)
             .await?
             .ok_or("No recurring task found for this task")?;
+        let recurring_task_before = recurring_task.clone();
 
         let task = self.task_repository.find_by_id(task_id).await?;
         let task = task.ok_or("Task not found")?;
 
         let base_date = task.due_at_utc.unwrap_or_else(Utc::now);
-        let next_due_at_utc =
-            self.calculate_due_date_for_base_date_and_frequency(base_date, &frequency, interval)?;
+        let next_due_at_utc = Self::calculate_next_due_date(base_date, &rule)?
+            .ok_or("The recurrence has no occurrence after the task's due date")?;
 
-        recurring_task.frequency = frequency.to_string();
-        recurring_task.interval = interval;
+        recurring_task.set_recurrence_rule(&rule);
+        if let Some(anchor) = anchor {
+            recurring_task.anchor = anchor.to_string();
+        }
         recurring_task.next_due_at_utc = next_due_at_utc;
+        Self::set_end_conditions(&mut recurring_task, until_utc, remaining_occurrences)?;
+        recurring_task.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Update recurring task");
+        operation.record_recurring_task(Some(recurring_task_before), Some(recurring_task.clone()));
+        self.undo_repository.commit(&operation).await?;
 
-        self.recurring_task_repository
-            .save(&mut recurring_task)
-            .await?;
         Ok(recurring_task)
     }
 
+    fn set_end_conditions(
+        recurring_task: &mut RecurringTask,
+        until_utc: Option<DateTime<Utc>>,
+        remaining_occurrences: Option<i32>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if remaining_occurrences.is_some_and(|remaining_occurrences| remaining_occurrences <= 0) {
+            return Err("The number of occurrences must be positive".into());
+        }
+
+        recurring_task.until_utc = until_utc;
+        recurring_task.remaining_occurrences = remaining_occurrences;
+        if !recurring_task.has_occurrence_at(recurring_task.next_due_at_utc) {
+            return Err("The recurrence ends before its next occurrence".into());
+        }
+
+        Ok(())
+    }
+
+    /// The next `count` occurrences after `base_date`, fewer when the rule's UNTIL ends
+    /// the recurrence first. Each occurrence steps from the previous one, as completing
+    /// the tasks of the series does, so a day clamped to the end of a shorter month is
+    /// kept: Jan 31st gives Feb 28th then Mar 28th.
+    pub fn preview_occurrences(
+        base_date: DateTime<Utc>,
+        rule: &RecurrenceRule,
+        count: usize,
+    ) -> Result<Vec<DateTime<Utc>>, Box<dyn std::error::Error>> {
+        let mut occurrences = Vec::new();
+        let mut date = base_date;
+        while occurrences.len() < count {
+            match Self::calculate_next_due_date(date, rule)? {
+                Some(next_date) => {
+                    occurrences.push(next_date);
+                    date = next_date;
+                }
+                None => break,
+            }
+        }
+        Ok(occurrences)
+    }
+
+    /// Next due date after `base_date`, None once the rule's UNTIL is passed.
+    /// Plain frequencies keep their original date arithmetic.
+    fn calculate_next_due_date(
+        base_date: DateTime<Utc>,
+        rule: &RecurrenceRule,
+    ) -> Result<Option<DateTime<Utc>>, Box<dyn std::error::Error>> {
+        if rule.has_by_rules() {
+            return Ok(rule.next_after(base_date));
+        }
+
+        let next_due = Self::calculate_due_date_for_base_date_and_frequency(
+            base_date,
+            &rule.frequency,
+            rule.interval,
+        )?;
+        Ok(Some(next_due).filter(|next_due| rule.until.is_none_or(|until| *next_due <= until)))
+    }
+
+    /// Months and years that do not have the base date's day are clamped to their last day,
+    /// Jan 31st + 1 month being Feb 28th (or 29th in leap years).
     fn calculate_due_date_for_base_date_and_frequency(
-        &self,
         base_date: DateTime<Utc>,
         frequency: &Frequency,
         interval: i32,
     ) -> Result<DateTime<Utc>, Box<dyn std::error::Error>> {
         let next_due = match frequency {
-            Frequency::Daily => base_date + chrono::Duration::days(interval as i64),
-            Frequency::Weekly => base_date + chrono::Duration::weeks(interval as i64),
-            Frequency::Monthly => {
-                // Add months by adjusting the month number
-                let total_months = base_date.month0() as i32 + interval;
-                let years_to_add = total_months / 12;
-                let final_month = (total_months % 12) as u32;
-
-                base_date
-                    .date_naive()
-                    .with_month0(final_month)
-                    .and_then(|d| d.with_year(base_date.year() + years_to_add))
-                    .map(|d| DateTime::from_naive_utc_and_offset(d.and_time(base_date.time()), Utc))
-                    .unwrap_or(base_date)
-            }
-            Frequency::Yearly => base_date
-                .date_naive()
-                .with_year(base_date.year() + interval)
-                .map(|d| DateTime::from_naive_utc_and_offset(d.and_time(base_date.time()), Utc))
-                .unwrap_or(base_date),
+            Frequency::Daily => chrono::Duration::try_days(interval.into())
+                .and_then(|duration| base_date.checked_add_signed(duration)),
+            Frequency::Weekly => chrono::Duration::try_weeks(interval.into())
+                .and_then(|duration| base_date.checked_add_signed(duration)),
+            Frequency::Monthly => u32::try_from(interval)
+                .ok()
+                .and_then(|months| base_date.checked_add_months(Months::new(months))),
+            Frequency::Yearly => u32::try_from(interval)
+                .ok()
+                .and_then(|years| years.checked_mul(12))
+                .and_then(|months| base_date.checked_add_months(Months::new(months))),
         };
 
-        Ok(next_due)
+        Ok(next_due.ok_or(OUT_OF_RANGE)?)
     }
 }
@@ -5,11 +5,25 @@ use strum_macros;
 use uuid::fmt::Hyphenated;
 use uuid::Uuid;
 
+use rule::RecurrenceRule;
+
 pub mod manager;
 pub mod repository;
+pub mod rule;
 pub mod tauri;
+mod test;
 
-#[derive(Debug, Serialize, Deserialize, Clone, strum_macros::Display, strum_macros::EnumString)]
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    strum_macros::Display,
+    strum_macros::EnumString,
+)]
 #[strum(serialize_all = "lowercase")]
 pub enum Frequency {
     Daily,
@@ -18,7 +32,29 @@ pub enum Frequency {
     Yearly,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+// What the next occurrence of a recurring task is counted from
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    strum_macros::Display,
+    strum_macros::EnumString,
+)]
+#[strum(serialize_all = "lowercase")]
+pub enum RecurrenceAnchor {
+    // The date the completed occurrence was due, keeping the series on its schedule
+    #[default]
+    Scheduled,
+    // The date the occurrence was actually completed
+    Completion,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
 pub struct RecurringTask {
     #[sqlx(try_from = "Hyphenated")]
     pub id: Uuid,
@@ -26,6 +62,12 @@ pub struct RecurringTask {
     pub task_id: Uuid,
     pub frequency: String, // Will be converted to/from Frequency enum
     pub interval: i32,
+    // Set for recurrences the frequency and interval cannot express, see `RecurrenceRule`
+    pub rule: Option<String>,
+    pub anchor: String, // Will be converted to/from RecurrenceAnchor enum
+    pub until_utc: Option<DateTime<Utc>>,
+    // Occurrences left to create, including the one due at `next_due_at_utc`
+    pub remaining_occurrences: Option<i32>,
     pub next_due_at_utc: DateTime<Utc>,
     pub created_at_utc: DateTime<Utc>,
     pub updated_at_utc: DateTime<Utc>,
@@ -44,6 +86,10 @@ impl RecurringTask {
             task_id,
             frequency: frequency.to_string(),
             interval,
+            rule: None,
+            anchor: RecurrenceAnchor::default().to_string(),
+            until_utc: None,
+            remaining_occurrences: None,
             next_due_at_utc,
             created_at_utc: now,
             updated_at_utc: now,
@@ -53,4 +99,28 @@ impl RecurringTask {
     pub fn frequency(&self) -> Result<Frequency, strum::ParseError> {
         self.frequency.parse()
     }
+
+    pub fn anchor(&self) -> Result<RecurrenceAnchor, strum::ParseError> {
+        self.anchor.parse()
+    }
+
+    // Whether the end conditions leave room for an occurrence due at `date`
+    pub fn has_occurrence_at(&self, date: DateTime<Utc>) -> bool {
+        self.remaining_occurrences
+            .is_none_or(|remaining_occurrences| remaining_occurrences > 0)
+            && self.until_utc.is_none_or(|until_utc| date <= until_utc)
+    }
+
+    pub fn recurrence_rule(&self) -> Result<RecurrenceRule, Box<dyn std::error::Error>> {
+        match &self.rule {
+            Some(rule) => Ok(rule.parse()?),
+            None => Ok(RecurrenceRule::new(self.frequency()?, self.interval)),
+        }
+    }
+
+    pub fn set_recurrence_rule(&mut self, rule: &RecurrenceRule) {
+        self.frequency = rule.frequency.to_string();
+        self.interval = rule.interval;
+        self.rule = (!rule.is_plain_frequency()).then(|| rule.to_string());
+    }
 }
@@ -43,11 +43,15 @@ impl RecurringTaskRepository for SqliteRecurringTaskRepository {
 
         if exists {
             sqlx::query(
-                "UPDATE recurring_tasks SET task_id = ?1, frequency = ?2, interval = ?3, next_due_at_utc = ?4, updated_at_utc = ?5 WHERE id = ?6"
+                "UPDATE recurring_tasks SET task_id = ?1, frequency = ?2, interval = ?3, rule = ?4, anchor = ?5, until_utc = ?6, remaining_occurrences = ?7, next_due_at_utc = ?8, updated_at_utc = ?9 WHERE id = ?10"
             )
             .bind(recurring_task.task_id.to_string())
             .bind(&recurring_task.frequency)
             .bind(recurring_task.interval)
+            .bind(&recurring_task.rule)
+            .bind(&recurring_task.anchor)
+            .bind(recurring_task.until_utc.map(|date| date.to_rfc3339()))
+            .bind(recurring_task.remaining_occurrences)
             .bind(recurring_task.next_due_at_utc.to_rfc3339())
             .bind(recurring_task.updated_at_utc.to_rfc3339())
             .bind(recurring_task.id.to_string())
@@ -55,12 +59,16 @@ impl RecurringTaskRepository for SqliteRecurringTaskRepository {
             .await?;
         } else {
             sqlx::query(
-                "INSERT INTO recurring_tasks (id, task_id, frequency, interval, next_due_at_utc, created_at_utc, updated_at_utc) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"
+                "INSERT INTO recurring_tasks (id, task_id, frequency, interval, rule, anchor, until_utc, remaining_occurrences, next_due_at_utc, created_at_utc, updated_at_utc) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)"
             )
             .bind(recurring_task.id.to_string())
             .bind(recurring_task.task_id.to_string())
             .bind(&recurring_task.frequency)
             .bind(recurring_task.interval)
+            .bind(&recurring_task.rule)
+            .bind(&recurring_task.anchor)
+            .bind(recurring_task.until_utc.map(|date| date.to_rfc3339()))
+            .bind(recurring_task.remaining_occurrences)
             .bind(recurring_task.next_due_at_utc.to_rfc3339())
             .bind(recurring_task.created_at_utc.to_rfc3339())
             .bind(recurring_task.updated_at_utc.to_rfc3339())
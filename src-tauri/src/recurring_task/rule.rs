@@ -0,0 +1,435 @@
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, Utc, Weekday};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+use super::Frequency;
+
+// Periods searched for a matching date before a rule is considered to have no
+// further occurrences, enough for e.g. every Feb 29th on a daily rule
+const MAX_PERIODS: u32 = 5000;
+
+#[derive(Error, Debug)]
+#[error("{0}")]
+pub struct RecurrenceRuleError(String);
+
+/// A weekday of a BYDAY part, optionally numbered within the month or year
+/// (`2TU` for the second Tuesday, `-1FR` for the last Friday).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByDay {
+    pub ordinal: Option<i32>,
+    pub weekday: Weekday,
+}
+
+/// The subset of RFC 5545 recurrence rules supported for recurring tasks.
+/// A rule with only a frequency and an interval behaves like the plain
+/// `Frequency` settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    pub interval: i32,
+    pub by_day: Vec<ByDay>,
+    pub by_month_day: Vec<i32>,
+    pub by_month: Vec<u32>,
+    pub by_set_pos: Vec<i32>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl RecurrenceRule {
+    pub fn new(frequency: Frequency, interval: i32) -> Self {
+        Self {
+            frequency,
+            interval,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            until: None,
+        }
+    }
+
+    // Plain rules are fully described by the frequency and interval columns
+    pub fn is_plain_frequency(&self) -> bool {
+        !self.has_by_rules() && self.until.is_none()
+    }
+
+    pub fn has_by_rules(&self) -> bool {
+        !self.by_day.is_empty()
+            || !self.by_month_day.is_empty()
+            || !self.by_month.is_empty()
+            || !self.by_set_pos.is_empty()
+    }
+
+    /// First date matching the rule strictly after `base_date`, the periods
+    /// being counted from the one containing `base_date`. The time of day is
+    /// kept from `base_date`.
+    pub fn next_after(&self, base_date: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let base_day = base_date.date_naive();
+
+        for period in 0..MAX_PERIODS {
+            let step = period.checked_mul(self.interval as u32)?;
+            for day in self.candidates(base_day, step)? {
+                let candidate = DateTime::from_naive_utc_and_offset(
+                    NaiveDateTime::new(day, base_date.time()),
+                    Utc,
+                );
+                if candidate <= base_date {
+                    continue;
+                }
+                if self.until.is_some_and(|until| candidate > until) {
+                    return None;
+                }
+                return Some(candidate);
+            }
+        }
+
+        None
+    }
+
+    // Sorted dates of the period `step` periods after the one holding `base_day`
+    fn candidates(&self, base_day: NaiveDate, step: u32) -> Option<Vec<NaiveDate>> {
+        let mut days = match self.frequency {
+            Frequency::Daily => {
+                let day = base_day.checked_add_days(Days::new(step.into()))?;
+                self.filter_days(vec![day])
+            }
+            Frequency::Weekly => {
+                let week_start = base_day
+                    .checked_sub_days(Days::new(base_day.weekday().num_days_from_monday().into()))?
+                    .checked_add_days(Days::new(u64::from(step) * 7))?;
+                let week: Vec<NaiveDate> = week_start.iter_days().take(7).collect();
+                let week = if self.by_day.is_empty() {
+                    week.into_iter()
+                        .filter(|day| day.weekday() == base_day.weekday())
+                        .collect()
+                } else {
+                    week
+                };
+                self.filter_days(week)
+            }
+            Frequency::Monthly => {
+                let month_start = base_day
+                    .with_day(1)?
+                    .checked_add_months(Months::new(step))?;
+                self.expand_month(month_start, base_day)
+            }
+            Frequency::Yearly => {
+                let year = base_day.year().checked_add(step as i32)?;
+                if !self.by_month.is_empty() {
+                    let mut months = self.by_month.clone();
+                    months.sort();
+                    months.dedup();
+                    let mut days = Vec::new();
+                    for month in months {
+                        let month_start = NaiveDate::from_ymd_opt(year, month, 1)?;
+                        days.extend(self.expand_month(month_start, base_day));
+                    }
+                    days
+                } else if self.by_day.is_empty() && self.by_month_day.is_empty() {
+                    NaiveDate::from_ymd_opt(year, base_day.month(), base_day.day())
+                        .into_iter()
+                        .collect()
+                } else {
+                    let year_days = NaiveDate::from_ymd_opt(year, 1, 1)?
+                        .iter_days()
+                        .take_while(|day| day.year() == year)
+                        .collect();
+                    self.filter_days(year_days)
+                }
+            }
+        };
+
+        days.sort();
+        Some(self.apply_set_pos(days))
+    }
+
+    fn expand_month(&self, month_start: NaiveDate, base_day: NaiveDate) -> Vec<NaiveDate> {
+        let month_days: Vec<NaiveDate> = month_start
+            .iter_days()
+            .take_while(|day| day.month() == month_start.month())
+            .collect();
+
+        // Without BYDAY or BYMONTHDAY the rule repeats on the day of the base date
+        if self.by_day.is_empty() && self.by_month_day.is_empty() {
+            return month_days
+                .into_iter()
+                .filter(|day| day.day() == base_day.day() && self.matches_month(day))
+                .collect();
+        }
+
+        self.filter_days(month_days)
+    }
+
+    // Keeps the days of a period matching the BYMONTH, BYMONTHDAY and BYDAY parts,
+    // BYDAY ordinals counting within the given days
+    fn filter_days(&self, days: Vec<NaiveDate>) -> Vec<NaiveDate> {
+        let days: Vec<NaiveDate> = days
+            .into_iter()
+            .filter(|day| self.matches_month(day) && self.matches_month_day(day))
+            .collect();
+
+        if self.by_day.is_empty() {
+            return days;
+        }
+
+        let mut matching_days = Vec::new();
+        for by_day in &self.by_day {
+            let weekdays: Vec<NaiveDate> = days
+                .iter()
+                .copied()
+                .filter(|day| day.weekday() == by_day.weekday)
+                .collect();
+            match by_day.ordinal {
+                None => matching_days.extend(weekdays),
+                Some(ordinal) => matching_days.extend(pick(&weekdays, ordinal)),
+            }
+        }
+        matching_days.sort();
+        matching_days.dedup();
+        matching_days
+    }
+
+    fn matches_month(&self, day: &NaiveDate) -> bool {
+        self.by_month.is_empty() || self.by_month.contains(&day.month())
+    }
+
+    fn matches_month_day(&self, day: &NaiveDate) -> bool {
+        if self.by_month_day.is_empty() {
+            return true;
+        }
+
+        let days_in_month = day
+            .with_day(1)
+            .and_then(|month_start| month_start.checked_add_months(Months::new(1)))
+            .and_then(|next_month| next_month.pred_opt())
+            .map_or(31, |last_day| last_day.day() as i32);
+        self.by_month_day.iter().any(|&month_day| {
+            let month_day = if month_day < 0 {
+                days_in_month + 1 + month_day
+            } else {
+                month_day
+            };
+            day.day() as i32 == month_day
+        })
+    }
+
+    fn apply_set_pos(&self, days: Vec<NaiveDate>) -> Vec<NaiveDate> {
+        if self.by_set_pos.is_empty() {
+            return days;
+        }
+
+        let mut selected_days: Vec<NaiveDate> = self
+            .by_set_pos
+            .iter()
+            .filter_map(|&position| pick(&days, position))
+            .collect();
+        selected_days.sort();
+        selected_days.dedup();
+        selected_days
+    }
+}
+
+// 1 is the first element, -1 the last
+fn pick(days: &[NaiveDate], position: i32) -> Option<NaiveDate> {
+    let index = if position > 0 {
+        position as usize - 1
+    } else {
+        days.len().checked_sub(position.unsigned_abs() as usize)?
+    };
+    days.get(index).copied()
+}
+
+fn parse_weekday(value: &str) -> Result<Weekday, RecurrenceRuleError> {
+    match value {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        _ => Err(RecurrenceRuleError(format!("Invalid weekday: {}", value))),
+    }
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn parse_list<T: FromStr>(
+    value: &str,
+    is_valid: impl Fn(&T) -> bool,
+    part: &str,
+) -> Result<Vec<T>, RecurrenceRuleError> {
+    value
+        .split(',')
+        .map(|item| {
+            item.parse::<T>()
+                .ok()
+                .filter(|number| is_valid(number))
+                .ok_or_else(|| RecurrenceRuleError(format!("Invalid {} value: {}", part, item)))
+        })
+        .collect()
+}
+
+fn parse_by_day(value: &str) -> Result<ByDay, RecurrenceRuleError> {
+    let (ordinal, weekday) = value.split_at(value.len().saturating_sub(2));
+    let ordinal = match ordinal {
+        "" => None,
+        ordinal => Some(
+            ordinal
+                .trim_start_matches('+')
+                .parse::<i32>()
+                .ok()
+                .filter(|ordinal| *ordinal != 0 && ordinal.abs() <= 53)
+                .ok_or_else(|| RecurrenceRuleError(format!("Invalid BYDAY value: {}", value)))?,
+        ),
+    };
+
+    Ok(ByDay {
+        ordinal,
+        weekday: parse_weekday(weekday)?,
+    })
+}
+
+// UNTIL is either a UTC date-time or a date, which includes the whole day
+fn parse_until(value: &str) -> Result<DateTime<Utc>, RecurrenceRuleError> {
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Ok(date_time.and_utc());
+    }
+
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(23, 59, 59))
+        .map(|date_time| date_time.and_utc())
+        .ok_or_else(|| RecurrenceRuleError(format!("Invalid UNTIL value: {}", value)))
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = RecurrenceRuleError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let value = value.strip_prefix("RRULE:").unwrap_or(value);
+
+        let mut frequency = None;
+        let mut rule = RecurrenceRule::new(Frequency::Daily, 1);
+
+        for part in value.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| RecurrenceRuleError(format!("Invalid rule part: {}", part)))?;
+            let name = name.to_ascii_uppercase();
+            let value = value.to_ascii_uppercase();
+
+            match name.as_str() {
+                "FREQ" => {
+                    frequency = Some(value.to_lowercase().parse::<Frequency>().map_err(|_| {
+                        RecurrenceRuleError(format!("Unsupported frequency: {}", value))
+                    })?)
+                }
+                "INTERVAL" => {
+                    rule.interval = value
+                        .parse()
+                        .ok()
+                        .filter(|interval| *interval > 0)
+                        .ok_or_else(|| {
+                            RecurrenceRuleError(format!("Invalid INTERVAL value: {}", value))
+                        })?
+                }
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .map(parse_by_day)
+                        .collect::<Result<_, _>>()?
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = parse_list(
+                        &value,
+                        |day: &i32| *day != 0 && day.abs() <= 31,
+                        "BYMONTHDAY",
+                    )?
+                }
+                "BYMONTH" => {
+                    rule.by_month =
+                        parse_list(&value, |month: &u32| (1..=12).contains(month), "BYMONTH")?
+                }
+                "BYSETPOS" => {
+                    rule.by_set_pos = parse_list(
+                        &value,
+                        |position: &i32| *position != 0 && position.abs() <= 366,
+                        "BYSETPOS",
+                    )?
+                }
+                "UNTIL" => rule.until = Some(parse_until(&value)?),
+                _ => {
+                    return Err(RecurrenceRuleError(format!(
+                        "Unsupported rule part: {}",
+                        name
+                    )))
+                }
+            }
+        }
+
+        rule.frequency = frequency
+            .ok_or_else(|| RecurrenceRuleError("The rule needs a FREQ part".to_string()))?;
+
+        let has_ordinals = rule.by_day.iter().any(|by_day| by_day.ordinal.is_some());
+        if has_ordinals && !matches!(rule.frequency, Frequency::Monthly | Frequency::Yearly) {
+            return Err(RecurrenceRuleError(
+                "Numbered BYDAY values need a monthly or yearly rule".to_string(),
+            ));
+        }
+
+        Ok(rule)
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |values: Vec<String>| values.join(",");
+
+        write!(
+            f,
+            "FREQ={};INTERVAL={}",
+            self.frequency.to_string().to_uppercase(),
+            self.interval
+        )?;
+        if !self.by_day.is_empty() {
+            let by_day = self
+                .by_day
+                .iter()
+                .map(|by_day| match by_day.ordinal {
+                    Some(ordinal) => format!("{}{}", ordinal, weekday_code(by_day.weekday)),
+                    None => weekday_code(by_day.weekday).to_string(),
+                })
+                .collect();
+            write!(f, ";BYDAY={}", join(by_day))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let by_month_day = self.by_month_day.iter().map(i32::to_string).collect();
+            write!(f, ";BYMONTHDAY={}", join(by_month_day))?;
+        }
+        if !self.by_month.is_empty() {
+            let by_month = self.by_month.iter().map(u32::to_string).collect();
+            write!(f, ";BYMONTH={}", join(by_month))?;
+        }
+        if !self.by_set_pos.is_empty() {
+            let by_set_pos = self.by_set_pos.iter().map(i32::to_string).collect();
+            write!(f, ";BYSETPOS={}", join(by_set_pos))?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+
+        Ok(())
+    }
+}
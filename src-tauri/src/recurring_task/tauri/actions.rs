@@ -1,9 +1,11 @@
+use chrono::{DateTime, Utc};
 use tauri::State;
 use uuid::Uuid;
 
 use crate::errors::handle_error;
 use crate::recurring_task::manager::RecurringTaskManager;
-use crate::recurring_task::Frequency;
+use crate::recurring_task::rule::RecurrenceRule;
+use crate::recurring_task::{Frequency, RecurrenceAnchor};
 use crate::repository::RepositoryProvider;
 
 #[derive(serde::Deserialize)]
@@ -12,6 +14,12 @@ pub struct CreateRecurringTaskData {
     task_id: String,
     frequency: String,
     interval: i32,
+    // RRULE taking precedence over the frequency and interval when given
+    rule: Option<String>,
+    // "scheduled" when not given
+    anchor: Option<String>,
+    until_utc: Option<String>,
+    remaining_occurrences: Option<i32>,
 }
 
 #[derive(serde::Deserialize)]
@@ -20,6 +28,42 @@ pub struct UpdateRecurringTaskData {
     task_id: String,
     frequency: String,
     interval: i32,
+    rule: Option<String>,
+    // The current anchor is kept when not given
+    anchor: Option<String>,
+    until_utc: Option<String>,
+    remaining_occurrences: Option<i32>,
+}
+
+fn recurrence_anchor(anchor: Option<&str>) -> Result<Option<RecurrenceAnchor>, String> {
+    anchor
+        .map(|anchor| anchor.parse::<RecurrenceAnchor>())
+        .transpose()
+        .map_err(|e| handle_error(&e))
+}
+
+fn until_date(until_utc: Option<&str>) -> Result<Option<DateTime<Utc>>, String> {
+    until_utc
+        .map(|date| DateTime::parse_from_rfc3339(date).map(|date| date.with_timezone(&Utc)))
+        .transpose()
+        .map_err(|e| handle_error(&e))
+}
+
+pub(super) fn recurrence_rule(
+    frequency: &str,
+    interval: i32,
+    rule: Option<&str>,
+) -> Result<RecurrenceRule, String> {
+    match rule {
+        Some(rule) => rule.parse::<RecurrenceRule>().map_err(|e| handle_error(&e)),
+        None => {
+            let frequency: Frequency = frequency.parse().map_err(|e| handle_error(&e))?;
+            if interval < 1 {
+                return Err("The interval must be at least 1".to_string());
+            }
+            Ok(RecurrenceRule::new(frequency, interval))
+        }
+    }
 }
 
 #[tauri::command]
@@ -28,7 +72,9 @@ pub async fn setup_recurring_task_command(
     repository_provider: State<'_, RepositoryProvider>,
 ) -> Result<String, String> {
     let task_id = Uuid::parse_str(&data.task_id).map_err(|e| handle_error(&e))?;
-    let frequency: Frequency = data.frequency.parse().map_err(|e| handle_error(&e))?;
+    let rule = recurrence_rule(&data.frequency, data.interval, data.rule.as_deref())?;
+    let anchor = recurrence_anchor(data.anchor.as_deref())?.unwrap_or_default();
+    let until_utc = until_date(data.until_utc.as_deref())?;
 
     let mut task_repository = repository_provider
         .task_repository()
@@ -39,11 +85,25 @@ pub async fn setup_recurring_task_command(
         .await
         .map_err(|e| handle_error(&e))?;
 
-    let mut recurring_task_manager =
-        RecurringTaskManager::new(&mut recurring_task_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+
+    let mut recurring_task_manager = RecurringTaskManager::new(
+        &mut recurring_task_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let recurring_task = recurring_task_manager
-        .setup_recurring_task(task_id, frequency, data.interval)
+        .setup_recurring_task_with_rule(
+            task_id,
+            rule,
+            anchor,
+            until_utc,
+            data.remaining_occurrences,
+        )
         .await
         .map_err(|e| handle_error(&*e))?;
 
@@ -56,7 +116,9 @@ pub async fn update_recurring_task_command(
     repository_provider: State<'_, RepositoryProvider>,
 ) -> Result<String, String> {
     let task_id = Uuid::parse_str(&data.task_id).map_err(|e| handle_error(&e))?;
-    let frequency: Frequency = data.frequency.parse().map_err(|e| handle_error(&e))?;
+    let rule = recurrence_rule(&data.frequency, data.interval, data.rule.as_deref())?;
+    let anchor = recurrence_anchor(data.anchor.as_deref())?;
+    let until_utc = until_date(data.until_utc.as_deref())?;
 
     let mut task_repository = repository_provider
         .task_repository()
@@ -67,11 +129,25 @@ pub async fn update_recurring_task_command(
         .await
         .map_err(|e| handle_error(&e))?;
 
-    let mut recurring_task_manager =
-        RecurringTaskManager::new(&mut recurring_task_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+
+    let mut recurring_task_manager = RecurringTaskManager::new(
+        &mut recurring_task_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let recurring_task = recurring_task_manager
-        .update_recurring_task(task_id, frequency, data.interval)
+        .update_recurring_task_with_rule(
+            task_id,
+            rule,
+            anchor,
+            until_utc,
+            data.remaining_occurrences,
+        )
         .await
         .map_err(|e| handle_error(&*e))?;
 
@@ -94,11 +170,93 @@ pub async fn delete_recurring_task_command(
         .await
         .map_err(|e| handle_error(&e))?;
 
-    let mut recurring_task_manager =
-        RecurringTaskManager::new(&mut recurring_task_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+
+    let mut recurring_task_manager = RecurringTaskManager::new(
+        &mut recurring_task_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     recurring_task_manager
         .delete_recurring_task(task_id)
         .await
         .map_err(|e| handle_error(&*e))
 }
+
+#[tauri::command]
+pub async fn skip_occurrence_command(
+    task_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    let task_id = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut recurring_task_repository = repository_provider
+        .recurring_task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+
+    let mut undo_repository = repository_provider
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+
+    let mut recurring_task_manager = RecurringTaskManager::new(
+        &mut recurring_task_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+
+    let task = recurring_task_manager
+        .skip_occurrence(task_id)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    serde_json::to_string(&task).map_err(|e| handle_error(&e))
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn postpone_occurrence_command(
+    task_id: String,
+    due_date: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    let task_id = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let due_date = DateTime::parse_from_rfc3339(&due_date)
+        .map_err(|e| handle_error(&e))?
+        .with_timezone(&Utc);
+
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut recurring_task_repository = repository_provider
+        .recurring_task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+
+    let mut undo_repository = repository_provider
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+
+    let mut recurring_task_manager = RecurringTaskManager::new(
+        &mut recurring_task_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+
+    let task = recurring_task_manager
+        .postpone_occurrence(task_id, due_date)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    serde_json::to_string(&task).map_err(|e| handle_error(&e))
+}
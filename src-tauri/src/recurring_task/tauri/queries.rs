@@ -1,7 +1,10 @@
+use chrono::{DateTime, Utc};
 use tauri::State;
 use uuid::Uuid;
 
+use super::actions::recurrence_rule;
 use crate::errors::handle_error;
+use crate::recurring_task::manager::RecurringTaskManager;
 use crate::recurring_task::repository::RecurringTaskRepository;
 use crate::repository::RepositoryProvider;
 
@@ -23,3 +26,30 @@ pub async fn get_recurring_task_command(
 
     serde_json::to_string(&recurring_task).map_err(|e| handle_error(&e))
 }
+
+const MAX_PREVIEW_OCCURRENCES: usize = 100;
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn preview_recurrence_command(
+    frequency: String,
+    interval: i32,
+    rule: Option<String>,
+    base_date: String,
+    count: usize,
+) -> Result<String, String> {
+    if !(1..=MAX_PREVIEW_OCCURRENCES).contains(&count) {
+        return Err(format!(
+            "Between 1 and {} occurrences can be previewed",
+            MAX_PREVIEW_OCCURRENCES
+        ));
+    }
+    let rule = recurrence_rule(&frequency, interval, rule.as_deref())?;
+    let base_date = DateTime::parse_from_rfc3339(&base_date)
+        .map_err(|e| handle_error(&e))?
+        .with_timezone(&Utc);
+
+    let occurrences = RecurringTaskManager::preview_occurrences(base_date, &rule, count)
+        .map_err(|e| handle_error(&*e))?;
+
+    serde_json::to_string(&occurrences).map_err(|e| handle_error(&e))
+}
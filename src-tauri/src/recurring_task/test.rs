@@ -0,0 +1,484 @@
+#[cfg(test)]
+mod rule_test {
+    use crate::recurring_task::rule::RecurrenceRule;
+    use crate::recurring_task::Frequency;
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    fn date(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 9, 0, 0).unwrap()
+    }
+
+    fn next_dates(rule: &str, base_date: DateTime<Utc>, count: usize) -> Vec<DateTime<Utc>> {
+        let rule: RecurrenceRule = rule.parse().unwrap();
+        let mut dates = Vec::new();
+        let mut date = base_date;
+        while dates.len() < count {
+            match rule.next_after(date) {
+                Some(next_date) => {
+                    dates.push(next_date);
+                    date = next_date;
+                }
+                None => break,
+            }
+        }
+        dates
+    }
+
+    #[test]
+    fn it_parses_and_formats_rules() {
+        let rule: RecurrenceRule = "RRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1"
+            .parse()
+            .unwrap();
+        assert_eq!(Frequency::Monthly, rule.frequency);
+        assert_eq!(1, rule.interval);
+        assert_eq!(5, rule.by_day.len());
+        assert_eq!(
+            "FREQ=MONTHLY;INTERVAL=1;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+            rule.to_string()
+        );
+        assert_eq!(rule, rule.to_string().parse().unwrap());
+
+        assert!(RecurrenceRule::new(Frequency::Weekly, 2).is_plain_frequency());
+        assert!("FREQ=HOURLY".parse::<RecurrenceRule>().is_err());
+        assert!("FREQ=WEEKLY;BYDAY=2TU".parse::<RecurrenceRule>().is_err());
+        assert!("INTERVAL=2".parse::<RecurrenceRule>().is_err());
+    }
+
+    #[test]
+    fn it_repeats_on_several_days_of_the_week() {
+        // Wednesday
+        let base_date = date(2025, 1, 1);
+
+        assert_eq!(
+            vec![date(2025, 1, 3), date(2025, 1, 6), date(2025, 1, 8)],
+            next_dates("FREQ=WEEKLY;BYDAY=MO,WE,FR", base_date, 3)
+        );
+    }
+
+    #[test]
+    fn it_repeats_on_a_numbered_weekday_of_the_month() {
+        assert_eq!(
+            vec![date(2025, 1, 14), date(2025, 2, 11), date(2025, 3, 11)],
+            next_dates("FREQ=MONTHLY;BYDAY=2TU", date(2025, 1, 1), 3)
+        );
+    }
+
+    #[test]
+    fn it_repeats_on_the_last_weekday_of_the_month() {
+        // May 31st 2025 is a Saturday
+        assert_eq!(
+            vec![date(2025, 4, 30), date(2025, 5, 30), date(2025, 6, 30)],
+            next_dates(
+                "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+                date(2025, 4, 1),
+                3
+            )
+        );
+    }
+
+    #[test]
+    fn it_stops_after_the_until_date() {
+        assert_eq!(
+            vec![date(2025, 4, 15), date(2025, 7, 15), date(2025, 10, 15)],
+            next_dates(
+                "FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=15;UNTIL=20251231",
+                date(2025, 1, 15),
+                10
+            )
+        );
+    }
+}
+
+#[cfg(test)]
+mod manager_test {
+    use crate::recurring_task::manager::RecurringTaskManager;
+    use crate::recurring_task::repository::RecurringTaskRepository;
+    use crate::recurring_task::rule::RecurrenceRule;
+    use crate::recurring_task::{Frequency, RecurrenceAnchor};
+    use crate::repository::RepositoryProvider;
+    use crate::task::manager::TaskManager;
+    use crate::task::CreateTaskData;
+
+    use chrono::{DateTime, TimeZone, Utc};
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    #[tokio::test]
+    async fn completing_a_task_follows_its_recurrence_rule_until_it_ends() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(CreateTaskData {
+                title: "Pay rent".to_string(),
+                description: None,
+                project_id: None,
+                due_at_utc: Some(
+                    Utc.with_ymd_and_hms(2025, 10, 1, 9, 0, 0)
+                        .unwrap()
+                        .to_rfc3339(),
+                ),
+                priority: None,
+            })
+            .await
+            .unwrap();
+
+        let mut recurring_task_repository = provider.recurring_task_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let recurring_task = RecurringTaskManager::new(
+            &mut recurring_task_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        )
+        .setup_recurring_task_with_rule(
+            task.id,
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=1;UNTIL=20251130"
+                .parse()
+                .unwrap(),
+            RecurrenceAnchor::Scheduled,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!("monthly", recurring_task.frequency);
+        assert_eq!(
+            Utc.with_ymd_and_hms(2025, 11, 3, 9, 0, 0).unwrap(),
+            recurring_task.next_due_at_utc
+        );
+
+        task_manager.complete_task(task.id).await.unwrap();
+
+        // The November occurrence is the last one, December is past the rule's end
+        let inbox = task_manager.load_inbox().await.unwrap();
+        assert_eq!(1, inbox.len());
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2025, 11, 3, 9, 0, 0).unwrap()),
+            inbox[0].due_at_utc
+        );
+        assert!(recurring_task_repository
+            .find_by_task_id(inbox[0].id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    fn date(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 10, day, hour, 0, 0).unwrap()
+    }
+
+    // Completes a weekly task due on October 1st at 9:00, on the given date
+    async fn next_due_date_when_completed_at(
+        anchor: RecurrenceAnchor,
+        completed_at: DateTime<Utc>,
+    ) -> (Option<DateTime<Utc>>, DateTime<Utc>) {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(CreateTaskData {
+                title: "Water the plants".to_string(),
+                description: None,
+                project_id: None,
+                due_at_utc: Some(date(1, 9).to_rfc3339()),
+                priority: None,
+            })
+            .await
+            .unwrap();
+
+        let mut recurring_task_repository = provider.recurring_task_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        RecurringTaskManager::new(
+            &mut recurring_task_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        )
+        .setup_recurring_task_with_rule(
+            task.id,
+            RecurrenceRule::new(Frequency::Weekly, 1),
+            anchor,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        task_manager
+            .complete_task_at(task.id, completed_at)
+            .await
+            .unwrap();
+        let next_task = task_manager.load_inbox().await.unwrap().remove(0);
+        let recurring_task = recurring_task_repository
+            .find_by_task_id(next_task.id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        (next_task.due_at_utc, recurring_task.next_due_at_utc)
+    }
+
+    #[tokio::test]
+    async fn scheduled_recurrences_keep_their_schedule_however_late_or_early_they_are_completed() {
+        assert_eq!(
+            (Some(date(8, 9)), date(15, 9)),
+            next_due_date_when_completed_at(RecurrenceAnchor::Scheduled, date(10, 18)).await
+        );
+        assert_eq!(
+            (Some(date(8, 9)), date(15, 9)),
+            next_due_date_when_completed_at(
+                RecurrenceAnchor::Scheduled,
+                Utc.with_ymd_and_hms(2025, 9, 29, 18, 0, 0).unwrap()
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn completion_recurrences_restart_from_the_completion_date() {
+        // Completed late, the next occurrence is a week after the completion at the usual time
+        assert_eq!(
+            (Some(date(17, 9)), date(24, 9)),
+            next_due_date_when_completed_at(RecurrenceAnchor::Completion, date(10, 18)).await
+        );
+        // Completed early, the next occurrence comes sooner than scheduled
+        assert_eq!(
+            (Some(date(6, 9)), date(13, 9)),
+            next_due_date_when_completed_at(
+                RecurrenceAnchor::Completion,
+                Utc.with_ymd_and_hms(2025, 9, 29, 18, 0, 0).unwrap()
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn it_stops_generating_occurrences_once_they_run_out() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(CreateTaskData {
+                title: "Physiotherapy".to_string(),
+                description: None,
+                project_id: None,
+                due_at_utc: Some(date(1, 9).to_rfc3339()),
+                priority: None,
+            })
+            .await
+            .unwrap();
+
+        let mut recurring_task_repository = provider.recurring_task_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut manager = RecurringTaskManager::new(
+            &mut recurring_task_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        assert!(manager
+            .setup_recurring_task_with_rule(
+                task.id,
+                RecurrenceRule::new(Frequency::Weekly, 1),
+                RecurrenceAnchor::Scheduled,
+                Some(date(7, 9)),
+                None,
+            )
+            .await
+            .is_err());
+        manager
+            .setup_recurring_task_with_rule(
+                task.id,
+                RecurrenceRule::new(Frequency::Weekly, 1),
+                RecurrenceAnchor::Scheduled,
+                Some(date(31, 9)),
+                Some(2),
+            )
+            .await
+            .unwrap();
+
+        task_manager.complete_task(task.id).await.unwrap();
+        let inbox = task_manager.load_inbox().await.unwrap();
+        assert_eq!(Some(date(8, 9)), inbox[0].due_at_utc);
+        let recurring_task = recurring_task_repository
+            .find_by_task_id(inbox[0].id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(Some(1), recurring_task.remaining_occurrences);
+
+        // The last occurrence is created without recurring settings
+        task_manager.complete_task(inbox[0].id).await.unwrap();
+        let inbox = task_manager.load_inbox().await.unwrap();
+        assert_eq!(1, inbox.len());
+        assert_eq!(Some(date(15, 9)), inbox[0].due_at_utc);
+        assert!(recurring_task_repository
+            .find_by_task_id(inbox[0].id)
+            .await
+            .unwrap()
+            .is_none());
+
+        task_manager.complete_task(inbox[0].id).await.unwrap();
+        assert!(task_manager.load_inbox().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn skipping_and_postponing_occurrences_leaves_no_completion() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(CreateTaskData {
+                title: "Team meeting".to_string(),
+                description: None,
+                project_id: None,
+                due_at_utc: Some(date(1, 9).to_rfc3339()),
+                priority: None,
+            })
+            .await
+            .unwrap();
+
+        let mut recurring_task_repository = provider.recurring_task_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut manager = RecurringTaskManager::new(
+            &mut recurring_task_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        manager
+            .setup_recurring_task(task.id, Frequency::Weekly, 1)
+            .await
+            .unwrap();
+
+        let task = manager.skip_occurrence(task.id).await.unwrap();
+        assert_eq!(Some(date(8, 9)), task.due_at_utc);
+        assert!(task.completed_at_utc.is_none());
+
+        // Postponing keeps the series on its schedule
+        assert!(manager
+            .postpone_occurrence(task.id, date(15, 9))
+            .await
+            .is_err());
+        let task = manager
+            .postpone_occurrence(task.id, date(10, 9))
+            .await
+            .unwrap();
+        assert_eq!(Some(date(10, 9)), task.due_at_utc);
+
+        let recurring_task = recurring_task_repository
+            .find_by_task_id(task.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(date(15, 9), recurring_task.next_due_at_utc);
+        assert!(task_manager
+            .load_completed_tasks()
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_previews_the_occurrences_the_series_will_get() {
+        let base_date = Utc.with_ymd_and_hms(2025, 1, 31, 9, 0, 0).unwrap();
+        let preview = RecurringTaskManager::preview_occurrences(
+            base_date,
+            &RecurrenceRule::new(Frequency::Monthly, 1),
+            3,
+        )
+        .unwrap();
+        // The day clamped to the end of February is kept for the following months
+        assert_eq!(
+            vec![
+                Utc.with_ymd_and_hms(2025, 2, 28, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 3, 28, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 4, 28, 9, 0, 0).unwrap(),
+            ],
+            preview
+        );
+
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(CreateTaskData {
+                title: "Send invoices".to_string(),
+                description: None,
+                project_id: None,
+                due_at_utc: Some(base_date.to_rfc3339()),
+                priority: None,
+            })
+            .await
+            .unwrap();
+        let mut recurring_task_repository = provider.recurring_task_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        RecurringTaskManager::new(
+            &mut recurring_task_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        )
+        .setup_recurring_task(task.id, Frequency::Monthly, 1)
+        .await
+        .unwrap();
+
+        let mut task_id = task.id;
+        let mut due_dates = Vec::new();
+        for _ in 0..3 {
+            task_manager.complete_task(task_id).await.unwrap();
+            let next_task = task_manager.load_inbox().await.unwrap().remove(0);
+            due_dates.push(next_task.due_at_utc.unwrap());
+            task_id = next_task.id;
+        }
+        assert_eq!(preview, due_dates);
+
+        assert_eq!(
+            vec![
+                Utc.with_ymd_and_hms(2025, 2, 28, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2025, 3, 31, 9, 0, 0).unwrap(),
+            ],
+            RecurringTaskManager::preview_occurrences(
+                base_date,
+                &"FREQ=MONTHLY;BYMONTHDAY=-1".parse().unwrap(),
+                2
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            vec![Utc.with_ymd_and_hms(2025, 2, 28, 9, 0, 0).unwrap()],
+            RecurringTaskManager::preview_occurrences(
+                Utc.with_ymd_and_hms(2024, 2, 29, 9, 0, 0).unwrap(),
+                &"FREQ=YEARLY;UNTIL=20260101".parse().unwrap(),
+                5
+            )
+            .unwrap()
+        );
+
+        assert!(RecurringTaskManager::preview_occurrences(
+            base_date,
+            &RecurrenceRule::new(Frequency::Daily, i32::MAX),
+            2
+        )
+        .is_err());
+        assert!(RecurringTaskManager::preview_occurrences(
+            base_date,
+            &RecurrenceRule::new(Frequency::Yearly, i32::MAX),
+            1
+        )
+        .is_err());
+    }
+}
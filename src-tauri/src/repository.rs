@@ -1,8 +1,16 @@
 use sqlx::{Pool, Sqlite};
 
+use crate::history::repository::{HistoryRepository, SqliteHistoryRepository};
+use crate::label::repository::{LabelRepository, SqliteLabelRepository};
+use crate::pomodoro::repository::{PomodoroSessionRepository, SqlitePomodoroSessionRepository};
 use crate::project::repository::{ProjectRepository, SqliteProjectRepository};
 use crate::recurring_task::repository::{RecurringTaskRepository, SqliteRecurringTaskRepository};
+use crate::saved_view::repository::{SavedViewRepository, SqliteSavedViewRepository};
+use crate::search::repository::{SearchRepository, SqliteSearchRepository};
 use crate::task::repository::{SqliteTaskRepository, TaskRepository};
+use crate::template::repository::{SqliteTemplateRepository, TemplateRepository};
+use crate::time_entry::repository::{SqliteTimeEntryRepository, TimeEntryRepository};
+use crate::undo::repository::{SqliteUndoRepository, UndoRepository};
 
 pub struct RepositoryProvider {
     pub pool: Pool<Sqlite>,
@@ -29,4 +37,46 @@ impl RepositoryProvider {
         let connection = self.pool.acquire().await?;
         Ok(SqliteRecurringTaskRepository::new(connection))
     }
+
+    pub async fn label_repository(&self) -> Result<impl LabelRepository, sqlx::Error> {
+        let connection = self.pool.acquire().await?;
+        Ok(SqliteLabelRepository::new(connection))
+    }
+
+    pub async fn search_repository(&self) -> Result<impl SearchRepository, sqlx::Error> {
+        let connection = self.pool.acquire().await?;
+        Ok(SqliteSearchRepository::new(connection))
+    }
+
+    pub async fn saved_view_repository(&self) -> Result<impl SavedViewRepository, sqlx::Error> {
+        let connection = self.pool.acquire().await?;
+        Ok(SqliteSavedViewRepository::new(connection))
+    }
+
+    pub async fn history_repository(&self) -> Result<impl HistoryRepository, sqlx::Error> {
+        let connection = self.pool.acquire().await?;
+        Ok(SqliteHistoryRepository::new(connection))
+    }
+
+    pub async fn undo_repository(&self) -> Result<impl UndoRepository, sqlx::Error> {
+        let connection = self.pool.acquire().await?;
+        Ok(SqliteUndoRepository::new(connection))
+    }
+
+    pub async fn template_repository(&self) -> Result<impl TemplateRepository, sqlx::Error> {
+        let connection = self.pool.acquire().await?;
+        Ok(SqliteTemplateRepository::new(connection))
+    }
+
+    pub async fn time_entry_repository(&self) -> Result<impl TimeEntryRepository, sqlx::Error> {
+        let connection = self.pool.acquire().await?;
+        Ok(SqliteTimeEntryRepository::new(connection))
+    }
+
+    pub async fn pomodoro_session_repository(
+        &self,
+    ) -> Result<impl PomodoroSessionRepository, sqlx::Error> {
+        let connection = self.pool.acquire().await?;
+        Ok(SqlitePomodoroSessionRepository::new(connection))
+    }
 }
@@ -0,0 +1,103 @@
+use chrono::Utc;
+use std::error::Error;
+use uuid::Uuid;
+
+use super::repository::SavedViewRepository;
+use super::SavedView;
+use crate::task::filter::{self, TaskSortOrder};
+use crate::task::repository::TaskRepository;
+use crate::task::Task;
+
+pub struct SavedViewManager<'a> {
+    saved_view_repository: &'a mut dyn SavedViewRepository,
+    task_repository: &'a mut dyn TaskRepository,
+}
+
+impl<'a> SavedViewManager<'a> {
+    pub fn new(
+        saved_view_repository: &'a mut dyn SavedViewRepository,
+        task_repository: &'a mut dyn TaskRepository,
+    ) -> Self {
+        Self {
+            saved_view_repository,
+            task_repository,
+        }
+    }
+
+    pub async fn load_all(&mut self) -> Result<Vec<SavedView>, Box<dyn Error>> {
+        self.saved_view_repository
+            .find_all()
+            .await
+            .map_err(|e| e.into())
+    }
+
+    pub async fn create_view(
+        &mut self,
+        name: String,
+        icon: Option<String>,
+        filter_expression: String,
+        sort_order: TaskSortOrder,
+    ) -> Result<SavedView, Box<dyn Error>> {
+        // Reject filters that would fail every time the view is opened
+        filter::parse(&filter_expression)?;
+
+        let mut saved_view = SavedView::new(name, icon, filter_expression, sort_order);
+        self.saved_view_repository.save(&mut saved_view).await?;
+
+        Ok(saved_view)
+    }
+
+    pub async fn update_view(
+        &mut self,
+        view_id: Uuid,
+        name: String,
+        icon: Option<String>,
+        filter_expression: String,
+        sort_order: TaskSortOrder,
+    ) -> Result<SavedView, Box<dyn Error>> {
+        filter::parse(&filter_expression)?;
+
+        let mut saved_view = self
+            .saved_view_repository
+            .find_by_id(view_id)
+            .await?
+            .ok_or("View not found")?;
+
+        saved_view.name = name;
+        saved_view.icon = icon;
+        saved_view.filter = filter_expression;
+        saved_view.sort_order = sort_order.to_string();
+
+        self.saved_view_repository.save(&mut saved_view).await?;
+
+        Ok(saved_view)
+    }
+
+    pub async fn delete_view(&mut self, view_id: Uuid) -> Result<(), Box<dyn Error>> {
+        let saved_view = self
+            .saved_view_repository
+            .find_by_id(view_id)
+            .await?
+            .ok_or("View not found")?;
+
+        self.saved_view_repository.delete(&saved_view).await?;
+
+        Ok(())
+    }
+
+    pub async fn load_view_tasks(&mut self, view_id: Uuid) -> Result<Vec<Task>, Box<dyn Error>> {
+        let saved_view = self
+            .saved_view_repository
+            .find_by_id(view_id)
+            .await?
+            .ok_or("View not found")?;
+
+        let expression = filter::parse(&saved_view.filter)?;
+        let compiled_filter = filter::compile(&expression, Utc::now())?;
+
+        self.task_repository
+            .find_by_filter(&compiled_filter, saved_view.sort_order()?)
+            .await
+            .map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::{fmt::Hyphenated, Uuid};
+
+use crate::task::filter::TaskSortOrder;
+
+pub mod manager;
+pub mod repository;
+pub mod tauri;
+mod test;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct SavedView {
+    #[sqlx(try_from = "Hyphenated")]
+    pub id: Uuid,
+    pub name: String,
+    pub icon: Option<String>,
+    pub filter: String,
+    pub sort_order: String, // Will be converted to/from TaskSortOrder enum
+    pub created_at_utc: DateTime<Utc>,
+    pub updated_at_utc: DateTime<Utc>,
+}
+
+impl SavedView {
+    pub fn new(
+        name: String,
+        icon: Option<String>,
+        filter: String,
+        sort_order: TaskSortOrder,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::now_v7(),
+            name,
+            icon,
+            filter,
+            sort_order: sort_order.to_string(),
+            created_at_utc: now,
+            updated_at_utc: now,
+        }
+    }
+
+    pub fn sort_order(&self) -> Result<TaskSortOrder, strum::ParseError> {
+        self.sort_order.parse()
+    }
+}
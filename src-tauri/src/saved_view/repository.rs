@@ -0,0 +1,88 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{pool::PoolConnection, Sqlite};
+use uuid::Uuid;
+
+use super::SavedView;
+
+#[async_trait]
+pub trait SavedViewRepository: Send + Sync {
+    async fn save(&mut self, saved_view: &mut SavedView) -> Result<(), sqlx::Error>;
+    async fn delete(&mut self, saved_view: &SavedView) -> Result<(), sqlx::Error>;
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<SavedView>, sqlx::Error>;
+    async fn find_all(&mut self) -> Result<Vec<SavedView>, sqlx::Error>;
+}
+
+pub struct SqliteSavedViewRepository {
+    connection: PoolConnection<Sqlite>,
+}
+
+impl SqliteSavedViewRepository {
+    pub fn new(connection: PoolConnection<Sqlite>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl SavedViewRepository for SqliteSavedViewRepository {
+    async fn save(&mut self, saved_view: &mut SavedView) -> Result<(), sqlx::Error> {
+        let exists = sqlx::query("SELECT 1 FROM saved_views WHERE id = ?1 LIMIT 1")
+            .bind(saved_view.id.to_string())
+            .fetch_optional(&mut *self.connection)
+            .await?
+            .is_some();
+
+        saved_view.updated_at_utc = Utc::now();
+
+        if exists {
+            sqlx::query(
+                "UPDATE saved_views SET name = ?1, icon = ?2, filter = ?3, sort_order = ?4, updated_at_utc = ?5 WHERE id = ?6"
+            )
+            .bind(&saved_view.name)
+            .bind(&saved_view.icon)
+            .bind(&saved_view.filter)
+            .bind(&saved_view.sort_order)
+            .bind(saved_view.updated_at_utc.to_rfc3339())
+            .bind(saved_view.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+        } else {
+            sqlx::query(
+                "INSERT INTO saved_views (id, name, icon, filter, sort_order, created_at_utc, updated_at_utc) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"
+            )
+            .bind(saved_view.id.to_string())
+            .bind(&saved_view.name)
+            .bind(&saved_view.icon)
+            .bind(&saved_view.filter)
+            .bind(&saved_view.sort_order)
+            .bind(saved_view.created_at_utc.to_rfc3339())
+            .bind(saved_view.updated_at_utc.to_rfc3339())
+            .execute(&mut *self.connection)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&mut self, saved_view: &SavedView) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM saved_views WHERE id = ?1")
+            .bind(saved_view.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<SavedView>, sqlx::Error> {
+        sqlx::query_as::<_, SavedView>("SELECT * FROM saved_views WHERE id = ?1 LIMIT 1")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.connection)
+            .await
+    }
+
+    async fn find_all(&mut self) -> Result<Vec<SavedView>, sqlx::Error> {
+        sqlx::query_as::<_, SavedView>("SELECT * FROM saved_views ORDER BY created_at_utc ASC")
+            .fetch_all(&mut *self.connection)
+            .await
+    }
+}
@@ -0,0 +1,107 @@
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::repository::RepositoryProvider;
+use crate::saved_view::manager::SavedViewManager;
+use crate::task::filter::TaskSortOrder;
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn create_view_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    name: String,
+    icon: Option<String>,
+    filter: String,
+    sort_order: Option<String>,
+) -> Result<String, String> {
+    log::debug!("Running create view command for: {} | {}", name, filter);
+
+    let sort_order: TaskSortOrder = sort_order
+        .map(|sort_order| sort_order.parse())
+        .transpose()
+        .map_err(|e| handle_error(&e))?
+        .unwrap_or_default();
+
+    let mut saved_view_repository = repository_provider
+        .saved_view_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut saved_view_manager =
+        SavedViewManager::new(&mut saved_view_repository, &mut task_repository);
+
+    let saved_view = saved_view_manager
+        .create_view(name, icon, filter, sort_order)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&saved_view).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn update_view_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    view_id: String,
+    name: String,
+    icon: Option<String>,
+    filter: String,
+    sort_order: Option<String>,
+) -> Result<String, String> {
+    log::debug!("Running update view command for: {} | {}", view_id, filter);
+
+    let view_uuid = Uuid::parse_str(&view_id).map_err(|e| handle_error(&e))?;
+    let sort_order: TaskSortOrder = sort_order
+        .map(|sort_order| sort_order.parse())
+        .transpose()
+        .map_err(|e| handle_error(&e))?
+        .unwrap_or_default();
+
+    let mut saved_view_repository = repository_provider
+        .saved_view_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut saved_view_manager =
+        SavedViewManager::new(&mut saved_view_repository, &mut task_repository);
+
+    let saved_view = saved_view_manager
+        .update_view(view_uuid, name, icon, filter, sort_order)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&saved_view).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn delete_view_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    view_id: String,
+) -> Result<String, String> {
+    log::debug!("Running delete view command for: {}", view_id);
+
+    let view_uuid = Uuid::parse_str(&view_id).map_err(|e| handle_error(&e))?;
+
+    let mut saved_view_repository = repository_provider
+        .saved_view_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut saved_view_manager =
+        SavedViewManager::new(&mut saved_view_repository, &mut task_repository);
+
+    saved_view_manager
+        .delete_view(view_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(format!("View with ID {} deleted successfully", &view_id))
+}
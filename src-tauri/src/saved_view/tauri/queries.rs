@@ -0,0 +1,59 @@
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::repository::RepositoryProvider;
+use crate::saved_view::manager::SavedViewManager;
+
+#[tauri::command]
+pub async fn load_views_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load views command");
+
+    let mut saved_view_repository = repository_provider
+        .saved_view_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut saved_view_manager =
+        SavedViewManager::new(&mut saved_view_repository, &mut task_repository);
+
+    let saved_views = saved_view_manager
+        .load_all()
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&saved_views).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn load_view_tasks_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    view_id: String,
+) -> Result<String, String> {
+    log::debug!("Running load view tasks command for: {}", view_id);
+
+    let view_uuid = Uuid::parse_str(&view_id).map_err(|e| handle_error(&e))?;
+
+    let mut saved_view_repository = repository_provider
+        .saved_view_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut saved_view_manager =
+        SavedViewManager::new(&mut saved_view_repository, &mut task_repository);
+
+    let tasks = saved_view_manager
+        .load_view_tasks(view_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&tasks).unwrap())
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod manager_test {
+    use crate::repository::RepositoryProvider;
+    use crate::saved_view::manager::SavedViewManager;
+    use crate::task::filter::TaskSortOrder;
+    use crate::task::manager::TaskManager;
+    use crate::task::{CreateTaskData, Priority};
+
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    #[tokio::test]
+    async fn it_creates_updates_and_deletes_a_view() {
+        let provider = setup_test_db().await.unwrap();
+        let mut saved_view_repository = provider.saved_view_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut manager = SavedViewManager::new(&mut saved_view_repository, &mut task_repository);
+
+        let view = manager
+            .create_view(
+                "This week".to_string(),
+                Some("📅".to_string()),
+                "due:<7d & !completed".to_string(),
+                TaskSortOrder::DueDate,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(TaskSortOrder::DueDate, view.sort_order().unwrap());
+
+        let updated_view = manager
+            .update_view(
+                view.id,
+                "Inbox".to_string(),
+                None,
+                "inbox".to_string(),
+                TaskSortOrder::Priority,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!("Inbox", updated_view.name);
+        assert_eq!("inbox", updated_view.filter);
+        assert_eq!(1, manager.load_all().await.unwrap().len());
+
+        manager.delete_view(view.id).await.unwrap();
+
+        assert!(manager.load_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_refuses_views_with_an_invalid_filter() {
+        let provider = setup_test_db().await.unwrap();
+        let mut saved_view_repository = provider.saved_view_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut manager = SavedViewManager::new(&mut saved_view_repository, &mut task_repository);
+
+        let result = manager
+            .create_view(
+                "Broken".to_string(),
+                None,
+                "due:<7d &".to_string(),
+                TaskSortOrder::Priority,
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert!(manager.load_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_loads_the_tasks_of_a_view_in_its_sort_order() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+
+        for (title, priority) in [("Beta", Priority::P1), ("Alpha", Priority::P3)] {
+            task_manager
+                .create_task(CreateTaskData {
+                    title: title.to_string(),
+                    description: None,
+                    project_id: None,
+                    due_at_utc: None,
+                    priority: Some(priority),
+                })
+                .await
+                .unwrap();
+        }
+
+        let mut saved_view_repository = provider.saved_view_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut manager = SavedViewManager::new(&mut saved_view_repository, &mut task_repository);
+
+        let by_title = manager
+            .create_view(
+                "By title".to_string(),
+                None,
+                "inbox & !completed".to_string(),
+                TaskSortOrder::Title,
+            )
+            .await
+            .unwrap();
+        let by_priority = manager
+            .create_view(
+                "By priority".to_string(),
+                None,
+                "inbox & !completed".to_string(),
+                TaskSortOrder::Priority,
+            )
+            .await
+            .unwrap();
+
+        let titles: Vec<String> = manager
+            .load_view_tasks(by_title.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.title)
+            .collect();
+        assert_eq!(vec!["Alpha", "Beta"], titles);
+
+        let titles: Vec<String> = manager
+            .load_view_tasks(by_priority.id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.title)
+            .collect();
+        assert_eq!(vec!["Beta", "Alpha"], titles);
+    }
+}
@@ -0,0 +1,33 @@
+use super::repository::SearchRepository;
+use super::{build_match_query, SearchFilters, SearchHit};
+use crate::repository::RepositoryProvider;
+use std::error::Error;
+
+pub struct SearchManager<'a> {
+    repository_provider: &'a RepositoryProvider,
+}
+
+impl<'a> SearchManager<'a> {
+    pub fn new(repository_provider: &'a RepositoryProvider) -> Self {
+        Self {
+            repository_provider,
+        }
+    }
+
+    pub async fn search(
+        &self,
+        input: &str,
+        filters: SearchFilters,
+    ) -> Result<Vec<SearchHit>, Box<dyn Error>> {
+        let match_query = match build_match_query(input) {
+            Some(match_query) => match_query,
+            None => return Ok(vec![]),
+        };
+
+        let mut repository = self.repository_provider.search_repository().await?;
+        repository
+            .search(&match_query, &filters)
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+pub mod manager;
+pub mod repository;
+pub mod tauri;
+mod test;
+
+#[derive(Debug, Serialize, Clone, PartialEq, strum_macros::Display, strum_macros::EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SearchEntityType {
+    Task,
+    Project,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchHit {
+    pub entity_type: SearchEntityType,
+    pub id: Uuid,
+    pub title: String,
+    pub snippet: String,
+    pub project_id: Option<Uuid>,
+    // bm25 score, lower is a better match
+    pub rank: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct SearchFilters {
+    pub project_id: Option<Uuid>,
+    pub only_incomplete: bool,
+    pub limit: Option<i64>,
+}
+
+/// Turns free text typed by the user into a safe FTS5 query: every word is
+/// quoted so punctuation cannot be read as query syntax, and the last word
+/// matches as a prefix so results show up while typing.
+pub fn build_match_query(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split_whitespace()
+        .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+        .collect();
+
+    if terms.is_empty() {
+        return None;
+    }
+
+    Some(format!("{}*", terms.join(" ")))
+}
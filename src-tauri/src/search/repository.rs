@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use sqlx::{pool::PoolConnection, Row, Sqlite};
+use uuid::Uuid;
+
+use super::{SearchEntityType, SearchFilters, SearchHit};
+
+const DEFAULT_SEARCH_LIMIT: i64 = 50;
+
+#[async_trait]
+pub trait SearchRepository: Send + Sync {
+    async fn search(
+        &mut self,
+        match_query: &str,
+        filters: &SearchFilters,
+    ) -> Result<Vec<SearchHit>, sqlx::Error>;
+}
+
+pub struct SqliteSearchRepository {
+    connection: PoolConnection<Sqlite>,
+}
+
+impl SqliteSearchRepository {
+    pub fn new(connection: PoolConnection<Sqlite>) -> Self {
+        Self { connection }
+    }
+
+    fn row_to_hit(row: sqlx::sqlite::SqliteRow) -> Result<SearchHit, sqlx::Error> {
+        let entity_type = row
+            .get::<String, _>("entity_type")
+            .parse::<SearchEntityType>()
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let id =
+            Uuid::parse_str(row.get("entity_id")).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let project_id = match row.get::<Option<String>, _>("project_id") {
+            Some(id) => Some(Uuid::parse_str(&id).map_err(|e| sqlx::Error::Decode(Box::new(e)))?),
+            None => None,
+        };
+
+        Ok(SearchHit {
+            entity_type,
+            id,
+            title: row.get("title"),
+            snippet: row.get("snippet"),
+            project_id,
+            rank: row.get("rank"),
+        })
+    }
+}
+
+#[async_trait]
+impl SearchRepository for SqliteSearchRepository {
+    async fn search(
+        &mut self,
+        match_query: &str,
+        filters: &SearchFilters,
+    ) -> Result<Vec<SearchHit>, sqlx::Error> {
+        let mut query = r#"
+            SELECT
+                search_index.entity_type,
+                search_index.entity_id,
+                search_index.title,
+                snippet(search_index, -1, '<mark>', '</mark>', '…', 12) AS snippet,
+                CASE search_index.entity_type WHEN 'task' THEN tasks.project_id ELSE projects.id END AS project_id,
+                bm25(search_index) AS rank
+            FROM search_index
+            LEFT JOIN tasks ON search_index.entity_type = 'task' AND tasks.id = search_index.entity_id
+            LEFT JOIN projects ON search_index.entity_type = 'project' AND projects.id = search_index.entity_id
+            WHERE search_index MATCH ?1
+            AND (search_index.entity_type = 'project' OR (tasks.deleted_at_utc IS NULL AND tasks.archived_at_utc IS NULL))"#
+            .to_string();
+
+        if filters.project_id.is_some() {
+            query += " AND ((search_index.entity_type = 'task' AND tasks.project_id = ?2) OR (search_index.entity_type = 'project' AND projects.id = ?2))";
+        }
+        if filters.only_incomplete {
+            query +=
+                " AND (search_index.entity_type = 'project' OR tasks.completed_at_utc IS NULL)";
+        }
+        query += " ORDER BY rank ASC LIMIT ?3";
+
+        let rows = sqlx::query(&query)
+            .bind(match_query)
+            .bind(filters.project_id.map(|id| id.to_string()))
+            .bind(filters.limit.unwrap_or(DEFAULT_SEARCH_LIMIT))
+            .fetch_all(&mut *self.connection)
+            .await?;
+
+        rows.into_iter().map(Self::row_to_hit).collect()
+    }
+}
@@ -0,0 +1 @@
+// Search actions will be added here in the future
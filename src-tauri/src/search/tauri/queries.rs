@@ -0,0 +1,44 @@
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::repository::RepositoryProvider;
+use crate::search::manager::SearchManager;
+use crate::search::SearchFilters;
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn search_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    query: String,
+    project_id: Option<String>,
+    only_incomplete: Option<bool>,
+    limit: Option<i64>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running search command - query: {:?}, project_id: {:?}, only_incomplete: {:?}",
+        query,
+        project_id,
+        only_incomplete
+    );
+
+    let project_uuid = project_id
+        .map(|id| Uuid::parse_str(&id))
+        .transpose()
+        .map_err(|e| handle_error(&e))?;
+
+    let manager = SearchManager::new(&repository_provider);
+
+    let hits = manager
+        .search(
+            &query,
+            SearchFilters {
+                project_id: project_uuid,
+                only_incomplete: only_incomplete.unwrap_or(false),
+                limit,
+            },
+        )
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&hits).unwrap())
+}
@@ -0,0 +1,248 @@
+#[cfg(test)]
+mod search_test {
+    use crate::project::manager::ProjectsManager;
+    use crate::repository::RepositoryProvider;
+    use crate::search::manager::SearchManager;
+    use crate::search::{build_match_query, SearchEntityType, SearchFilters};
+    use crate::task::manager::TaskManager;
+    use crate::task::{CreateTaskData, UpdatedTaskData};
+
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    fn task_data(
+        title: &str,
+        description: Option<&str>,
+        project_id: Option<String>,
+    ) -> CreateTaskData {
+        CreateTaskData {
+            title: title.to_string(),
+            description: description.map(|d| d.to_string()),
+            project_id,
+            due_at_utc: None,
+            priority: None,
+        }
+    }
+
+    #[test]
+    fn it_quotes_user_input_and_matches_the_last_word_as_prefix() {
+        assert_eq!(None, build_match_query("   "));
+        assert_eq!(
+            Some("\"buy\" \"milk\"*".to_string()),
+            build_match_query("buy milk")
+        );
+        assert_eq!(
+            Some("\"say\" \"\"\"hi\"\"\"*".to_string()),
+            build_match_query("say \"hi\"")
+        );
+    }
+
+    #[tokio::test]
+    async fn it_finds_tasks_and_projects_by_title_and_description() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+
+        let task = task_manager
+            .create_task(task_data("Buy groceries", Some("Milk and bread"), None))
+            .await
+            .unwrap();
+        task_manager
+            .create_task(task_data("Call the bank", None, None))
+            .await
+            .unwrap();
+
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut projects_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        let project = projects_manager
+            .create_project(
+                "Kitchen renovation".to_string(),
+                None,
+                None,
+                Some("New fridge for the milk".to_string()),
+            )
+            .await
+            .unwrap();
+
+        let search_manager = SearchManager::new(&provider);
+
+        let hits = search_manager
+            .search("milk", SearchFilters::default())
+            .await
+            .unwrap();
+        assert_eq!(2, hits.len());
+        assert!(hits
+            .iter()
+            .any(|hit| hit.entity_type == SearchEntityType::Task && hit.id == task.id));
+        assert!(hits
+            .iter()
+            .any(|hit| hit.entity_type == SearchEntityType::Project && hit.id == project.id));
+        assert!(hits.iter().all(|hit| hit.snippet.contains("<mark>")));
+
+        let prefix_hits = search_manager
+            .search("groc", SearchFilters::default())
+            .await
+            .unwrap();
+        assert_eq!(1, prefix_hits.len());
+        assert_eq!(task.id, prefix_hits[0].id);
+    }
+
+    #[tokio::test]
+    async fn it_keeps_the_index_in_sync_with_updates_and_deletes() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let search_manager = SearchManager::new(&provider);
+
+        let task = task_manager
+            .create_task(task_data("Write report", None, None))
+            .await
+            .unwrap();
+
+        task_manager
+            .update_task(
+                task.id,
+                UpdatedTaskData {
+                    title: "Write presentation".to_string(),
+                    description: None,
+                    project_id: None,
+                    due_date: None,
+                    priority: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let hits = search_manager
+            .search("report", SearchFilters::default())
+            .await
+            .unwrap();
+        assert!(hits.is_empty());
+
+        let hits = search_manager
+            .search("presentation", SearchFilters::default())
+            .await
+            .unwrap();
+        assert_eq!(1, hits.len());
+
+        task_manager.delete_task(task.id).await.unwrap();
+
+        let hits = search_manager
+            .search("presentation", SearchFilters::default())
+            .await
+            .unwrap();
+        assert!(hits.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_leaves_archived_tasks_out_of_the_results() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let search_manager = SearchManager::new(&provider);
+
+        let task = task_manager
+            .create_task(task_data("File taxes", None, None))
+            .await
+            .unwrap();
+        task_manager.complete_task(task.id).await.unwrap();
+        task_manager.archive_task(task.id).await.unwrap();
+
+        let hits = search_manager
+            .search("taxes", SearchFilters::default())
+            .await
+            .unwrap();
+        assert!(hits.is_empty());
+
+        task_manager.unarchive_task(task.id).await.unwrap();
+
+        let hits = search_manager
+            .search("taxes", SearchFilters::default())
+            .await
+            .unwrap();
+        assert_eq!(1, hits.len());
+        assert_eq!(task.id, hits[0].id);
+    }
+
+    #[tokio::test]
+    async fn it_scopes_results_to_a_project_and_to_incomplete_tasks() {
+        let provider = setup_test_db().await.unwrap();
+
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut projects_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        let project = projects_manager
+            .create_project("Work".to_string(), None, None, None)
+            .await
+            .unwrap();
+
+        let task_manager = TaskManager::new(&provider);
+        let project_task = task_manager
+            .create_task(task_data(
+                "Review invoice",
+                None,
+                Some(project.id.to_string()),
+            ))
+            .await
+            .unwrap();
+        let inbox_task = task_manager
+            .create_task(task_data("Pay invoice", None, None))
+            .await
+            .unwrap();
+
+        let search_manager = SearchManager::new(&provider);
+
+        let scoped_hits = search_manager
+            .search(
+                "invoice",
+                SearchFilters {
+                    project_id: Some(project.id),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(1, scoped_hits.len());
+        assert_eq!(project_task.id, scoped_hits[0].id);
+        assert_eq!(Some(project.id), scoped_hits[0].project_id);
+
+        task_manager.complete_task(inbox_task.id).await.unwrap();
+
+        let incomplete_hits = search_manager
+            .search(
+                "invoice",
+                SearchFilters {
+                    only_incomplete: true,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(1, incomplete_hits.len());
+        assert_eq!(project_task.id, incomplete_hits[0].id);
+    }
+}
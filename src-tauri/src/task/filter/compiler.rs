@@ -0,0 +1,177 @@
+use chrono::{DateTime, Days, NaiveDate, Utc};
+
+use super::{Comparison, DateCondition, DateValue, FilterError, FilterExpression, FilterTerm};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterParameter {
+    Text(String),
+    Integer(i64),
+}
+
+/// A `WHERE` clause against the `tasks` table. User supplied values only
+/// ever end up in `parameters`, bound to the `?` placeholders in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledFilter {
+    pub where_clause: String,
+    pub parameters: Vec<FilterParameter>,
+}
+
+struct Compiler {
+    today: NaiveDate,
+    now: DateTime<Utc>,
+    parameters: Vec<FilterParameter>,
+}
+
+impl Compiler {
+    fn compile_expression(&mut self, expression: &FilterExpression) -> Result<String, FilterError> {
+        Ok(match expression {
+            FilterExpression::And(left, right) => format!(
+                "({} AND {})",
+                self.compile_expression(left)?,
+                self.compile_expression(right)?
+            ),
+            FilterExpression::Or(left, right) => format!(
+                "({} OR {})",
+                self.compile_expression(left)?,
+                self.compile_expression(right)?
+            ),
+            FilterExpression::Not(inner) => {
+                format!("(NOT COALESCE({}, 0))", self.compile_expression(inner)?)
+            }
+            FilterExpression::Term(term) => self.compile_term(term)?,
+        })
+    }
+
+    fn compile_term(&mut self, term: &FilterTerm) -> Result<String, FilterError> {
+        Ok(match term {
+            FilterTerm::Completed => "tasks.completed_at_utc IS NOT NULL".to_string(),
+            FilterTerm::Inbox => "tasks.project_id IS NULL".to_string(),
+            FilterTerm::Subtask => "tasks.parent_task_id IS NOT NULL".to_string(),
+            FilterTerm::Overdue => {
+                self.bind_text(self.now.to_rfc3339());
+                "(tasks.due_at_utc < ? AND tasks.completed_at_utc IS NULL)".to_string()
+            }
+            FilterTerm::Blocked => "EXISTS (SELECT 1 FROM task_dependencies INNER JOIN tasks AS blockers ON blockers.id = task_dependencies.depends_on_task_id WHERE task_dependencies.task_id = tasks.id AND blockers.completed_at_utc IS NULL AND blockers.deleted_at_utc IS NULL AND blockers.archived_at_utc IS NULL)".to_string(),
+            FilterTerm::Project(title) => {
+                self.bind_text(title.clone());
+                "tasks.project_id IN (SELECT projects.id FROM projects WHERE projects.title = ? COLLATE NOCASE)".to_string()
+            }
+            FilterTerm::Label(name) => {
+                self.bind_text(name.clone());
+                "EXISTS (SELECT 1 FROM task_labels INNER JOIN labels ON labels.id = task_labels.label_id WHERE task_labels.task_id = tasks.id AND labels.name = ? COLLATE NOCASE)".to_string()
+            }
+            FilterTerm::Title(text) => {
+                let escaped = text
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_");
+                self.bind_text(format!("%{}%", escaped));
+                "tasks.title LIKE ? ESCAPE '\\'".to_string()
+            }
+            FilterTerm::Priority(comparison, level) => {
+                self.parameters.push(FilterParameter::Integer(*level));
+                let operator = match comparison {
+                    Comparison::Equal => "=",
+                    Comparison::Before => "<",
+                    Comparison::BeforeOrEqual => "<=",
+                    Comparison::After => ">",
+                    Comparison::AfterOrEqual => ">=",
+                };
+                format!("tasks.priority {} ?", operator)
+            }
+            FilterTerm::Due(condition) => {
+                self.compile_date_condition("tasks.due_at_utc", condition)?
+            }
+            FilterTerm::Created(condition) => {
+                self.compile_date_condition("tasks.created_at_utc", condition)?
+            }
+        })
+    }
+
+    // Dates compare by whole UTC days: `due:<=tomorrow` includes all of tomorrow
+    fn compile_date_condition(
+        &mut self,
+        column: &str,
+        condition: &DateCondition,
+    ) -> Result<String, FilterError> {
+        let (comparison, value) = match condition {
+            DateCondition::None => return Ok(format!("{} IS NULL", column)),
+            DateCondition::Compare(comparison, value) => (comparison, value),
+        };
+
+        let day = match value {
+            DateValue::Relative {
+                days,
+                position,
+                token,
+            } => {
+                let day = if *days >= 0 {
+                    self.today.checked_add_days(Days::new(days.unsigned_abs()))
+                } else {
+                    self.today.checked_sub_days(Days::new(days.unsigned_abs()))
+                };
+                day.ok_or_else(|| FilterError {
+                    message: format!("Relative date of {} days is out of range", days),
+                    position: *position,
+                    token: token.clone(),
+                })?
+            }
+            DateValue::Absolute(date) => *date,
+        };
+
+        let day_start = start_of_day(day);
+        let next_day_start = start_of_day(day.succ_opt().unwrap_or(day));
+
+        Ok(match comparison {
+            Comparison::Equal => {
+                self.bind_text(day_start.clone());
+                self.bind_text(next_day_start.clone());
+                format!("({column} >= ? AND {column} < ?)")
+            }
+            Comparison::Before => {
+                self.bind_text(day_start.clone());
+                format!("{} < ?", column)
+            }
+            Comparison::BeforeOrEqual => {
+                self.bind_text(next_day_start.clone());
+                format!("{} < ?", column)
+            }
+            Comparison::After => {
+                self.bind_text(next_day_start.clone());
+                format!("{} >= ?", column)
+            }
+            Comparison::AfterOrEqual => {
+                self.bind_text(day_start.clone());
+                format!("{} >= ?", column)
+            }
+        })
+    }
+
+    fn bind_text(&mut self, value: String) {
+        self.parameters.push(FilterParameter::Text(value));
+    }
+}
+
+fn start_of_day(date: NaiveDate) -> String {
+    DateTime::<Utc>::from_naive_utc_and_offset(date.and_hms_opt(0, 0, 0).unwrap(), Utc).to_rfc3339()
+}
+
+/// Compiles a parsed filter into a parameterized SQL condition, resolving
+/// relative dates against `now`. Fails on relative dates too far away to resolve.
+pub fn compile(
+    expression: &FilterExpression,
+    now: DateTime<Utc>,
+) -> Result<CompiledFilter, FilterError> {
+    let mut compiler = Compiler {
+        today: now.date_naive(),
+        now,
+        parameters: Vec::new(),
+    };
+
+    let where_clause = compiler.compile_expression(expression)?;
+
+    Ok(CompiledFilter {
+        where_clause,
+        parameters: compiler.parameters,
+    })
+}
@@ -0,0 +1,104 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+pub mod compiler;
+pub mod parser;
+mod test;
+
+pub use compiler::{compile, CompiledFilter, FilterParameter};
+pub use parser::parse;
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum_macros::Display,
+    strum_macros::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TaskSortOrder {
+    Priority,
+    DueDate,
+    CreatedAt,
+    UpdatedAt,
+    Title,
+    #[default]
+    Position,
+}
+
+impl TaskSortOrder {
+    pub fn order_by_clause(&self) -> &'static str {
+        match self {
+            TaskSortOrder::Priority => "priority ASC, position ASC",
+            TaskSortOrder::DueDate => "due_at_utc IS NULL, due_at_utc ASC, priority ASC",
+            TaskSortOrder::CreatedAt => "created_at_utc DESC",
+            TaskSortOrder::UpdatedAt => "updated_at_utc DESC",
+            TaskSortOrder::Title => "title COLLATE NOCASE ASC",
+            TaskSortOrder::Position => "project_id, parent_task_id, position ASC",
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+#[error("{message} (at column {}, near '{token}')", .position + 1)]
+pub struct FilterError {
+    pub message: String,
+    // Character offset of the offending token in the filter expression
+    pub position: usize,
+    pub token: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpression {
+    And(Box<FilterExpression>, Box<FilterExpression>),
+    Or(Box<FilterExpression>, Box<FilterExpression>),
+    Not(Box<FilterExpression>),
+    Term(FilterTerm),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterTerm {
+    Completed,
+    Inbox,
+    Overdue,
+    Blocked,
+    Subtask,
+    Project(String),
+    Label(String),
+    Title(String),
+    Priority(Comparison, i64),
+    Due(DateCondition),
+    Created(DateCondition),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Equal,
+    Before,
+    BeforeOrEqual,
+    After,
+    AfterOrEqual,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateCondition {
+    None,
+    Compare(Comparison, DateValue),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateValue {
+    // Offset in days from today, e.g. `today` is 0 and `7d` is 7. Where it was written is kept
+    // to point at it when the offset runs past the supported dates
+    Relative {
+        days: i64,
+        position: usize,
+        token: String,
+    },
+    Absolute(NaiveDate),
+}
@@ -0,0 +1,334 @@
+use chrono::NaiveDate;
+
+use super::{Comparison, DateCondition, DateValue, FilterError, FilterExpression, FilterTerm};
+
+const KNOWN_FILTERS: &str =
+    "completed, inbox, overdue, blocked, subtask, project:, label:, title:, priority:, due:, created:";
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    And,
+    Or,
+    Not,
+    OpenParen,
+    CloseParen,
+    Term { key: String, value: Option<String> },
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    position: usize,
+    text: String,
+}
+
+impl Token {
+    fn error(&self, message: impl Into<String>) -> FilterError {
+        FilterError {
+            message: message.into(),
+            position: self.position,
+            token: self.text.clone(),
+        }
+    }
+}
+
+fn is_operator(character: char) -> bool {
+    matches!(character, '&' | '|' | '!' | '(' | ')')
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, FilterError> {
+    let characters: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < characters.len() {
+        let character = characters[index];
+
+        if character.is_whitespace() {
+            index += 1;
+            continue;
+        }
+
+        if is_operator(character) {
+            let kind = match character {
+                '&' => TokenKind::And,
+                '|' => TokenKind::Or,
+                '!' => TokenKind::Not,
+                '(' => TokenKind::OpenParen,
+                _ => TokenKind::CloseParen,
+            };
+            tokens.push(Token {
+                kind,
+                position: index,
+                text: character.to_string(),
+            });
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        while index < characters.len()
+            && (characters[index].is_alphanumeric() || characters[index] == '_')
+        {
+            index += 1;
+        }
+
+        if start == index {
+            return Err(FilterError {
+                message: "Unexpected character".to_string(),
+                position: index,
+                token: character.to_string(),
+            });
+        }
+
+        let key: String = characters[start..index].iter().collect();
+        let mut value = None;
+
+        if index < characters.len() && characters[index] == ':' {
+            index += 1;
+
+            if index < characters.len() && characters[index] == '"' {
+                let quote_position = index;
+                index += 1;
+                let value_start = index;
+                while index < characters.len() && characters[index] != '"' {
+                    index += 1;
+                }
+                if index >= characters.len() {
+                    return Err(FilterError {
+                        message: "Unterminated quoted value".to_string(),
+                        position: quote_position,
+                        token: characters[quote_position..].iter().collect(),
+                    });
+                }
+                value = Some(characters[value_start..index].iter().collect());
+                index += 1;
+            } else {
+                let value_start = index;
+                while index < characters.len()
+                    && !characters[index].is_whitespace()
+                    && !matches!(characters[index], '&' | '|' | '(' | ')')
+                {
+                    index += 1;
+                }
+                value = Some(characters[value_start..index].iter().collect());
+            }
+        }
+
+        tokens.push(Token {
+            kind: TokenKind::Term { key, value },
+            position: start,
+            text: characters[start..index].iter().collect(),
+        });
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+    input_length: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.index).cloned();
+        self.index += 1;
+        token
+    }
+
+    fn end_of_input_error(&self, message: &str) -> FilterError {
+        FilterError {
+            message: message.to_string(),
+            position: self.input_length,
+            token: String::from("end of filter"),
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<FilterExpression, FilterError> {
+        let mut expression = self.parse_and()?;
+
+        while matches!(self.peek(), Some(token) if token.kind == TokenKind::Or) {
+            self.next();
+            let right = self.parse_and()?;
+            expression = FilterExpression::Or(Box::new(expression), Box::new(right));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_and(&mut self) -> Result<FilterExpression, FilterError> {
+        let mut expression = self.parse_unary()?;
+
+        while matches!(self.peek(), Some(token) if token.kind == TokenKind::And) {
+            self.next();
+            let right = self.parse_unary()?;
+            expression = FilterExpression::And(Box::new(expression), Box::new(right));
+        }
+
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<FilterExpression, FilterError> {
+        if matches!(self.peek(), Some(token) if token.kind == TokenKind::Not) {
+            self.next();
+            let expression = self.parse_unary()?;
+            return Ok(FilterExpression::Not(Box::new(expression)));
+        }
+
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<FilterExpression, FilterError> {
+        let token = self
+            .next()
+            .ok_or_else(|| self.end_of_input_error("Expected a filter"))?;
+
+        match &token.kind {
+            TokenKind::OpenParen => {
+                let expression = self.parse_or()?;
+                match self.next() {
+                    Some(Token {
+                        kind: TokenKind::CloseParen,
+                        ..
+                    }) => Ok(expression),
+                    Some(other) => Err(other.error("Expected ')'")),
+                    None => Err(self.end_of_input_error("Expected ')' to close the group")),
+                }
+            }
+            TokenKind::Term { key, value } => {
+                parse_term(&token, key, value.as_deref()).map(FilterExpression::Term)
+            }
+            _ => Err(token.error("Expected a filter")),
+        }
+    }
+}
+
+fn parse_term(token: &Token, key: &str, value: Option<&str>) -> Result<FilterTerm, FilterError> {
+    let key = key.to_lowercase();
+
+    let flag = |term: FilterTerm| match value {
+        None => Ok(term),
+        Some(_) => Err(token.error(format!("'{}' does not take a value", key))),
+    };
+    let required_value = || match value {
+        Some(value) if !value.is_empty() => Ok(value.to_string()),
+        _ => Err(token.error(format!("'{}' needs a value, e.g. {}:something", key, key))),
+    };
+
+    match key.as_str() {
+        "completed" => flag(FilterTerm::Completed),
+        "inbox" => flag(FilterTerm::Inbox),
+        "overdue" => flag(FilterTerm::Overdue),
+        "blocked" => flag(FilterTerm::Blocked),
+        "subtask" => flag(FilterTerm::Subtask),
+        "project" => Ok(FilterTerm::Project(required_value()?)),
+        "label" => Ok(FilterTerm::Label(required_value()?)),
+        "title" => Ok(FilterTerm::Title(required_value()?)),
+        "priority" => parse_priority(token, &required_value()?),
+        "due" => Ok(FilterTerm::Due(parse_date_condition(
+            token,
+            &required_value()?,
+        )?)),
+        "created" => Ok(FilterTerm::Created(parse_date_condition(
+            token,
+            &required_value()?,
+        )?)),
+        _ => Err(token.error(format!(
+            "Unknown filter '{}', expected one of: {}",
+            key, KNOWN_FILTERS
+        ))),
+    }
+}
+
+fn split_comparison(value: &str) -> (Comparison, &str) {
+    for (prefix, comparison) in [
+        ("<=", Comparison::BeforeOrEqual),
+        (">=", Comparison::AfterOrEqual),
+        ("<", Comparison::Before),
+        (">", Comparison::After),
+        ("=", Comparison::Equal),
+    ] {
+        if let Some(rest) = value.strip_prefix(prefix) {
+            return (comparison, rest);
+        }
+    }
+
+    (Comparison::Equal, value)
+}
+
+fn parse_priority(token: &Token, value: &str) -> Result<FilterTerm, FilterError> {
+    let (comparison, level) = split_comparison(value);
+    let level = level.trim_start_matches(['p', 'P']);
+
+    match level.parse::<i64>() {
+        Ok(level) if (1..=4).contains(&level) => Ok(FilterTerm::Priority(comparison, level)),
+        _ => Err(token.error(format!(
+            "Invalid priority '{}', expected a level between 1 and 4",
+            value
+        ))),
+    }
+}
+
+fn parse_date_condition(token: &Token, value: &str) -> Result<DateCondition, FilterError> {
+    if value.eq_ignore_ascii_case("none") {
+        return Ok(DateCondition::None);
+    }
+
+    let (comparison, date) = split_comparison(value);
+    let relative_date = |days| DateValue::Relative {
+        days,
+        position: token.position,
+        token: token.text.clone(),
+    };
+    let date_value = match date.to_lowercase().as_str() {
+        "today" => relative_date(0),
+        "tomorrow" => relative_date(1),
+        "yesterday" => relative_date(-1),
+        relative if relative.ends_with('d') || relative.ends_with('w') => {
+            let (amount, unit) = relative.split_at(relative.len() - 1);
+            let invalid = || token.error(format!("Invalid relative date '{}'", date));
+            let amount = amount.parse::<i64>().map_err(|_| invalid())?;
+            match unit {
+                "w" => relative_date(amount.checked_mul(7).ok_or_else(invalid)?),
+                _ => relative_date(amount),
+            }
+        }
+        absolute => DateValue::Absolute(NaiveDate::parse_from_str(absolute, "%Y-%m-%d").map_err(
+            |_| {
+                token.error(format!(
+                    "Invalid date '{}', expected today, tomorrow, yesterday, none, a relative date like 7d or 2w, or YYYY-MM-DD",
+                    date
+                ))
+            },
+        )?),
+    };
+
+    Ok(DateCondition::Compare(comparison, date_value))
+}
+
+/// Parses a filter expression such as `due:<7d & project:Work & !completed`.
+///
+/// Terms are combined with `&` (and), `|` (or), `!` (not) and parentheses,
+/// `&` binding tighter than `|`.
+pub fn parse(input: &str) -> Result<FilterExpression, FilterError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+        tokens,
+        index: 0,
+        input_length: input.chars().count(),
+    };
+
+    let expression = parser.parse_or()?;
+
+    if let Some(token) = parser.next() {
+        return Err(token.error("Expected '&' or '|' between filters"));
+    }
+
+    Ok(expression)
+}
@@ -0,0 +1,247 @@
+#[cfg(test)]
+mod filter_tests {
+    use crate::label::manager::LabelManager;
+    use crate::project::manager::ProjectsManager;
+    use crate::repository::RepositoryProvider;
+    use crate::task::filter::{
+        compile, parse, Comparison, DateCondition, DateValue, FilterExpression, FilterParameter,
+        FilterTerm, TaskSortOrder,
+    };
+    use crate::task::manager::TaskManager;
+    use crate::task::{CreateTaskData, Priority};
+
+    use chrono::{DateTime, Duration, Utc};
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    fn task_data(
+        title: &str,
+        project_id: Option<String>,
+        due_at_utc: Option<DateTime<Utc>>,
+    ) -> CreateTaskData {
+        CreateTaskData {
+            title: title.to_string(),
+            description: None,
+            project_id,
+            due_at_utc: due_at_utc.map(|date| date.to_rfc3339()),
+            priority: None,
+        }
+    }
+
+    #[test]
+    fn it_parses_terms_with_and_binding_tighter_than_or() {
+        let expression = parse("inbox | due:<7d & !completed").unwrap();
+
+        assert_eq!(
+            FilterExpression::Or(
+                Box::new(FilterExpression::Term(FilterTerm::Inbox)),
+                Box::new(FilterExpression::And(
+                    Box::new(FilterExpression::Term(FilterTerm::Due(
+                        DateCondition::Compare(
+                            Comparison::Before,
+                            DateValue::Relative {
+                                days: 7,
+                                position: 8,
+                                token: "due:<7d".to_string(),
+                            }
+                        )
+                    ))),
+                    Box::new(FilterExpression::Not(Box::new(FilterExpression::Term(
+                        FilterTerm::Completed
+                    )))),
+                )),
+            ),
+            expression
+        );
+    }
+
+    #[test]
+    fn it_parses_quoted_values_and_groups() {
+        let expression =
+            parse("(project:\"Side Projects\" | label:urgent) & priority:<=p2").unwrap();
+
+        assert_eq!(
+            FilterExpression::And(
+                Box::new(FilterExpression::Or(
+                    Box::new(FilterExpression::Term(FilterTerm::Project(
+                        "Side Projects".to_string()
+                    ))),
+                    Box::new(FilterExpression::Term(FilterTerm::Label(
+                        "urgent".to_string()
+                    ))),
+                )),
+                Box::new(FilterExpression::Term(FilterTerm::Priority(
+                    Comparison::BeforeOrEqual,
+                    2
+                ))),
+            ),
+            expression
+        );
+    }
+
+    #[test]
+    fn it_points_at_the_offending_token() {
+        let error = parse("due:<7d & colour:red").unwrap_err();
+        assert_eq!(10, error.position);
+        assert_eq!("colour:red", error.token);
+        assert!(error.to_string().contains("column 11"));
+
+        let error = parse("due:soon").unwrap_err();
+        assert_eq!(0, error.position);
+        assert!(error.message.contains("Invalid date 'soon'"));
+
+        let error = parse("due:<9223372036854775807w").unwrap_err();
+        assert_eq!(0, error.position);
+        assert!(error.message.contains("Invalid relative date"));
+
+        // Days too far away to resolve are reported when compiling against today
+        let error = compile(&parse("inbox & due:<99999999999d").unwrap(), Utc::now()).unwrap_err();
+        assert_eq!(8, error.position);
+        assert_eq!("due:<99999999999d", error.token);
+        assert!(error.message.contains("out of range"));
+
+        let error = parse("inbox completed").unwrap_err();
+        assert_eq!(6, error.position);
+        assert_eq!("completed", error.token);
+
+        let error = parse("(inbox & completed").unwrap_err();
+        assert_eq!(18, error.position);
+
+        let error = parse("project:\"Side").unwrap_err();
+        assert_eq!(8, error.position);
+
+        assert!(parse("completed:yes").is_err());
+        assert!(parse("priority:5").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn it_compiles_values_into_parameters_only() {
+        let now = DateTime::parse_from_rfc3339("2025-05-10T15:30:00+00:00")
+            .unwrap()
+            .with_timezone(&Utc);
+        let expression = parse("project:\"x' OR 1=1 --\" & due:<=tomorrow").unwrap();
+
+        let compiled = compile(&expression, now).unwrap();
+
+        assert!(!compiled.where_clause.contains("OR 1=1"));
+        assert_eq!(
+            vec![
+                FilterParameter::Text("x' OR 1=1 --".to_string()),
+                FilterParameter::Text("2025-05-12T00:00:00+00:00".to_string()),
+            ],
+            compiled.parameters
+        );
+        assert_eq!(
+            compiled.where_clause.matches('?').count(),
+            compiled.parameters.len()
+        );
+    }
+
+    #[tokio::test]
+    async fn it_loads_tasks_matching_a_filter() {
+        let provider = setup_test_db().await.unwrap();
+
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut projects_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        let work = projects_manager
+            .create_project("Work".to_string(), None, None, None)
+            .await
+            .unwrap();
+
+        let manager = TaskManager::new(&provider);
+        let soon = manager
+            .create_task(task_data(
+                "Soon",
+                Some(work.id.to_string()),
+                Some(Utc::now() + Duration::days(2)),
+            ))
+            .await
+            .unwrap();
+        let later = manager
+            .create_task(task_data(
+                "Later",
+                Some(work.id.to_string()),
+                Some(Utc::now() + Duration::days(30)),
+            ))
+            .await
+            .unwrap();
+        let done = manager
+            .create_task(task_data(
+                "Done",
+                Some(work.id.to_string()),
+                Some(Utc::now() + Duration::days(1)),
+            ))
+            .await
+            .unwrap();
+        manager
+            .create_task(task_data("Inbox", None, Some(Utc::now())))
+            .await
+            .unwrap();
+        manager.complete_task(done.id).await.unwrap();
+
+        let tasks = manager
+            .load_tasks_by_filter(
+                "due:<7d & project:work & !completed",
+                TaskSortOrder::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(1, tasks.len());
+        assert_eq!(soon.id, tasks[0].id);
+
+        let mut label_repository = provider.label_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+        let label = label_manager
+            .create_label("urgent".to_string(), None)
+            .await
+            .unwrap();
+        label_manager
+            .attach_label_to_task(label.id, later.id)
+            .await
+            .unwrap();
+
+        let tasks = manager
+            .load_tasks_by_filter("label:urgent | due:none", TaskSortOrder::default())
+            .await
+            .unwrap();
+        assert_eq!(1, tasks.len());
+        assert_eq!(later.id, tasks[0].id);
+        assert_eq!(Priority::P4, tasks[0].priority);
+
+        let tasks = manager
+            .load_tasks_by_filter("!due:none & !project:Work", TaskSortOrder::default())
+            .await
+            .unwrap();
+        assert_eq!(1, tasks.len());
+        assert_eq!("Inbox", tasks[0].title);
+
+        assert!(manager
+            .load_tasks_by_filter("due:<", TaskSortOrder::default())
+            .await
+            .is_err());
+    }
+}
@@ -1,8 +1,18 @@
+use super::filter::{self, TaskSortOrder};
+use super::position;
 use super::repository::TaskRepository;
-use super::{CreateTaskData, PeriodTaskStatistic, Task, UpdatedTaskData};
+use super::{
+    CreateTaskData, PeriodTaskStatistic, Task, TaskCompletion, TaskStatus, TaskTreeNode,
+    UpdatedTaskData,
+};
 use crate::recurring_task::manager::RecurringTaskManager;
+use crate::recurring_task::repository::RecurringTaskRepository;
+use crate::recurring_task::RecurringTask;
 use crate::repository::RepositoryProvider;
-use chrono::{DateTime, Utc};
+use crate::undo::repository::UndoRepository;
+use crate::undo::UndoOperation;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
 use std::error::Error;
 use thiserror::Error;
 use uuid::Uuid;
@@ -11,21 +21,39 @@ use uuid::Uuid;
 pub enum TaskError {
     #[error("Task not found")]
     TaskNotFound,
+    #[error("A task cannot depend on itself")]
+    SelfDependency,
+    #[error("Adding this dependency would create a cycle")]
+    DependencyCycle,
+    #[error("Task is not archived")]
+    TaskNotArchived,
+    #[error("Tasks can only be reordered within the same list")]
+    DifferentList,
+    #[error("A task cannot be moved under itself or one of its subtasks")]
+    ParentCycle,
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
 }
 
 pub struct TaskManager<'a> {
     repository_provider: &'a RepositoryProvider,
+    // Global default for tasks that do not set `auto_complete` themselves
+    auto_complete_parents: bool,
 }
 
 impl<'a> TaskManager<'a> {
     pub fn new(repository_provider: &'a RepositoryProvider) -> Self {
         Self {
             repository_provider,
+            auto_complete_parents: false,
         }
     }
 
+    pub fn with_auto_complete_parents(mut self, auto_complete_parents: bool) -> Self {
+        self.auto_complete_parents = auto_complete_parents;
+        self
+    }
+
     pub async fn create_task(
         &self,
         create_task_data: CreateTaskData,
@@ -47,8 +75,13 @@ impl<'a> TaskManager<'a> {
                 .transpose()?
                 .map(DateTime::<Utc>::from),
         );
+        task.priority = create_task_data.priority.unwrap_or_default();
+        task.position = repository.position_at_end(&task).await?;
+
+        let mut operation = UndoOperation::new("Create task");
+        operation.record_task(None, Some(task.clone()));
+        self.commit(&operation).await?;
 
-        repository.save(&mut task).await?;
         Ok(task)
     }
 
@@ -71,8 +104,13 @@ impl<'a> TaskManager<'a> {
                 .transpose()?
                 .map(DateTime::<Utc>::from),
         );
+        task.priority = create_task_data.priority.unwrap_or_default();
+        task.position = repository.position_at_end(&task).await?;
+
+        let mut operation = UndoOperation::new("Create subtask");
+        operation.record_task(None, Some(task.clone()));
+        self.commit(&operation).await?;
 
-        repository.save(&mut task).await?;
         Ok(task)
     }
 
@@ -95,17 +133,28 @@ impl<'a> TaskManager<'a> {
             Some(task) => task,
         };
 
-        // If the due date is being updated, handle recurring task update
+        let mut operation = UndoOperation::new("Update task");
+
+        // If the due date is being updated, the recurring task moves along with it
         if let Some(due_date) = &update_data.due_date {
             let new_due_date = DateTime::parse_from_rfc3339(due_date)?.with_timezone(&Utc);
-            let mut recurring_task_manager =
-                RecurringTaskManager::new(&mut recurring_task_repository, &mut task_repository);
-            recurring_task_manager
-                .handle_task_update(task_id, new_due_date)
-                .await?;
+            if let Some(recurring_task) = recurring_task_repository.find_by_task_id(task_id).await?
+            {
+                let recurring_task_after =
+                    RecurringTaskManager::rescheduled(recurring_task.clone(), new_due_date)?;
+                operation.record_recurring_task(Some(recurring_task), recurring_task_after);
+            }
+        }
+
+        let task_before = task.clone();
+        task.apply_update(update_data)?;
+        // A task moved to another project is placed at the end of its new list
+        if !task.shares_list_with(&task_before) {
+            task.position = task_repository.position_at_end(&task).await?;
         }
+        operation.record_task(Some(task_before), Some(task.clone()));
+        self.commit(&operation).await?;
 
-        task_repository.update_task(&mut task, update_data).await?;
         Ok(Some(task))
     }
 
@@ -117,10 +166,106 @@ impl<'a> TaskManager<'a> {
             None => return Ok(()),
         };
 
-        repository.delete(&task).await?;
+        let deleted_at = Utc::now();
+        let mut operation = UndoOperation::new("Delete task");
+        // Subtree rows come parents first. Subtasks already in the trash keep their own
+        // timestamp, with their subtasks, so they are restored separately
+        let mut deleted_ids = Vec::new();
+        for subtask in repository.find_subtree(task.id).await? {
+            let deleted_with_parent = subtask.deleted_at_utc.is_none()
+                && subtask
+                    .parent_task_id
+                    .is_some_and(|parent_task_id| deleted_ids.contains(&parent_task_id));
+            if subtask.id != task.id && !deleted_with_parent {
+                continue;
+            }
+
+            deleted_ids.push(subtask.id);
+            let mut deleted_subtask = subtask.clone();
+            deleted_subtask.deleted_at_utc = Some(deleted_at);
+            operation.record_task(Some(subtask), Some(deleted_subtask));
+        }
+        self.commit(&operation).await?;
+
         Ok(())
     }
 
+    pub async fn restore_task(&self, task_id: Uuid) -> Result<Task, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+
+        let mut task = repository
+            .find_by_id(task_id)
+            .await?
+            .filter(|task| task.deleted_at_utc.is_some())
+            .ok_or(TaskError::TaskNotFound)?;
+
+        let subtree_before = repository.find_subtree(task.id).await?;
+        let task_before = task.clone();
+        task.deleted_at_utc = None;
+
+        // A subtask whose parent is still in the trash comes back as a top-level task
+        if let Some(parent_task_id) = task.parent_task_id {
+            let parent_is_deleted = repository
+                .find_by_id(parent_task_id)
+                .await?
+                .is_none_or(|parent| parent.deleted_at_utc.is_some());
+
+            if parent_is_deleted {
+                task.parent_task_id = None;
+                task.updated_at_utc = Utc::now();
+                task.position = repository.position_at_end(&task).await?;
+            }
+        }
+
+        let mut operation = UndoOperation::new("Restore task");
+        operation.record_task(Some(task_before.clone()), Some(task.clone()));
+
+        // Only the subtasks that were trashed together with the task come back with it
+        let mut restored = HashMap::from([(task.id, task_before.deleted_at_utc)]);
+        for subtask in subtree_before.into_iter().skip(1) {
+            let trashed_with_parent = subtask
+                .parent_task_id
+                .and_then(|parent_task_id| restored.get(&parent_task_id))
+                .is_some_and(|deleted_at| *deleted_at == subtask.deleted_at_utc);
+            if !trashed_with_parent {
+                continue;
+            }
+
+            restored.insert(subtask.id, subtask.deleted_at_utc);
+            let mut restored_subtask = subtask.clone();
+            restored_subtask.deleted_at_utc = None;
+            operation.record_task(Some(subtask), Some(restored_subtask));
+        }
+        self.commit(&operation).await?;
+
+        repository
+            .find_by_id(task.id)
+            .await?
+            .ok_or_else(|| TaskError::TaskNotFound.into())
+    }
+
+    pub async fn load_trash(&self) -> Result<Vec<Task>, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        repository.find_deleted().await.map_err(Into::into)
+    }
+
+    pub async fn empty_trash(&self) -> Result<u64, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        repository
+            .purge_deleted_before(Utc::now())
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn purge_trash(&self, retention_days: u32) -> Result<u64, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        let cutoff = Utc::now() - Duration::days(retention_days.into());
+        repository
+            .purge_deleted_before(cutoff)
+            .await
+            .map_err(Into::into)
+    }
+
     pub async fn load_tasks(&self, include_completed: bool) -> Result<Vec<Task>, Box<dyn Error>> {
         let mut repository = self.repository_provider.task_repository().await?;
         repository
@@ -141,38 +286,215 @@ impl<'a> TaskManager<'a> {
             .map_err(Into::into)
     }
 
-    pub async fn complete_task(&self, task_id: Uuid) -> Result<(), Box<dyn Error>> {
+    pub async fn complete_task(&self, task_id: Uuid) -> Result<TaskCompletion, Box<dyn Error>> {
+        self.complete_task_at(task_id, Utc::now()).await
+    }
+
+    // Completes the task as of `completed_at`, which completion anchored recurrences restart from
+    pub async fn complete_task_at(
+        &self,
+        task_id: Uuid,
+        completed_at: DateTime<Utc>,
+    ) -> Result<TaskCompletion, Box<dyn Error>> {
         let mut task_repository = self.repository_provider.task_repository().await?;
-        let mut recurring_task_repository =
-            self.repository_provider.recurring_task_repository().await?;
 
-        let mut task = match task_repository.find_by_id(task_id).await? {
-            None => return Ok(()),
+        let task = match task_repository.find_by_id(task_id).await? {
+            None => {
+                return Ok(TaskCompletion {
+                    unblocked_tasks: vec![],
+                })
+            }
             Some(task) => task,
         };
 
         if task.completed_at_utc.is_some() {
             log::info!("Task was already completed, marking it incomplete");
             self.unmark_task_completed(task.id).await?;
-            return Ok(());
+            return Ok(TaskCompletion {
+                unblocked_tasks: vec![],
+            });
         }
 
-        let task_subtasks = task_repository.find_by_parent(task.id).await?;
+        let mut operation = UndoOperation::new("Complete task");
+        let parent_task_id = task.parent_task_id;
+        let mut dependents = self
+            .complete_with_subtasks(task, completed_at, &mut operation)
+            .await?;
+
+        // Completing the last open subtask can complete the parents up the tree. A recurring
+        // subtask leaves its next occurrence open, which keeps the parent open as well.
+        let mut next_parent_task_id = parent_task_id;
+        while let Some(parent_task_id) = next_parent_task_id {
+            let parent_task = match task_repository.find_by_id(parent_task_id).await? {
+                Some(parent_task) => Self::with_recorded_subtasks(parent_task, &operation),
+                None => break,
+            };
+            if !self.should_auto_complete(&parent_task) {
+                break;
+            }
+
+            next_parent_task_id = parent_task.parent_task_id;
+            dependents.extend(
+                self.complete_with_subtasks(parent_task, completed_at, &mut operation)
+                    .await?,
+            );
+        }
 
-        for mut subtask in task_subtasks {
-            subtask.completed_at_utc = Some(Utc::now());
-            task_repository.save(&mut subtask).await?;
+        self.commit(&operation).await?;
+
+        let mut unblocked_tasks: Vec<Task> = Vec::new();
+        for dependent in dependents {
+            if unblocked_tasks.iter().any(|task| task.id == dependent.id) {
+                continue;
+            }
+            if let Some(dependent) = task_repository.find_by_id(dependent.id).await? {
+                if dependent.completed_at_utc.is_none() && !dependent.blocked {
+                    unblocked_tasks.push(dependent);
+                }
+            }
         }
 
-        task.completed_at_utc = Some(Utc::now());
-        task_repository.save(&mut task).await?;
+        Ok(TaskCompletion { unblocked_tasks })
+    }
 
-        // Handle recurring task if it exists
-        let mut recurring_task_manager =
-            RecurringTaskManager::new(&mut recurring_task_repository, &mut task_repository);
-        recurring_task_manager.handle_task_completion(&task).await?;
+    pub async fn set_auto_complete(
+        &self,
+        task_id: Uuid,
+        auto_complete: Option<bool>,
+    ) -> Result<Task, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        let mut task = repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
 
-        Ok(())
+        let task_before = task.clone();
+        task.auto_complete = auto_complete;
+        task.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Change auto-completion");
+        operation.record_task(Some(task_before), Some(task.clone()));
+        self.commit(&operation).await?;
+
+        Ok(task)
+    }
+
+    pub async fn set_estimate(
+        &self,
+        task_id: Uuid,
+        estimated_minutes: Option<i64>,
+    ) -> Result<Task, Box<dyn Error>> {
+        if estimated_minutes.is_some_and(|minutes| minutes <= 0) {
+            return Err("Estimate must be a positive number of minutes".into());
+        }
+
+        let mut repository = self.repository_provider.task_repository().await?;
+        let mut task = repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
+
+        let task_before = task.clone();
+        task.estimated_minutes = estimated_minutes;
+        task.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Change estimate");
+        operation.record_task(Some(task_before), Some(task.clone()));
+        self.commit(&operation).await?;
+
+        Ok(task)
+    }
+
+    fn should_auto_complete(&self, task: &Task) -> bool {
+        task.status() == TaskStatus::Open
+            && task.auto_complete.unwrap_or(self.auto_complete_parents)
+            && task.subtask_total > 0
+            && task.subtask_completed == task.subtask_total
+    }
+
+    // Nothing is written before the whole completion is committed, so the rollups loaded for a
+    // parent are brought up to date with the subtasks the operation already changed
+    fn with_recorded_subtasks(mut parent_task: Task, operation: &UndoOperation) -> Task {
+        for (before, after) in operation.task_changes() {
+            for (snapshot, delta) in [(before, -1), (after, 1)] {
+                let Some(subtask) = snapshot.filter(|subtask| {
+                    subtask.parent_task_id == Some(parent_task.id)
+                        && subtask.deleted_at_utc.is_none()
+                        && subtask.archived_at_utc.is_none()
+                }) else {
+                    continue;
+                };
+
+                parent_task.subtask_total += delta;
+                if subtask.completed_at_utc.is_some() {
+                    parent_task.subtask_completed += delta;
+                }
+            }
+        }
+
+        parent_task
+    }
+
+    // Records the completion of a task with its open subtasks and the rollover of its
+    // recurrence to the next occurrence, returning the tasks that were waiting on anything
+    // completed here
+    async fn complete_with_subtasks(
+        &self,
+        mut task: Task,
+        completed_at: DateTime<Utc>,
+        operation: &mut UndoOperation,
+    ) -> Result<Vec<Task>, Box<dyn Error>> {
+        let mut task_repository = self.repository_provider.task_repository().await?;
+        let mut recurring_task_repository =
+            self.repository_provider.recurring_task_repository().await?;
+
+        // Subtasks completed earlier in the operation are already recorded
+        let task_subtasks: Vec<Task> = task_repository
+            .find_by_parent(task.id)
+            .await?
+            .into_iter()
+            .filter(|subtask| {
+                !operation
+                    .task_changes()
+                    .any(|(_, after)| after.is_some_and(|after| after.id == subtask.id))
+            })
+            .collect();
+
+        let mut tasks_before = vec![task.clone()];
+        tasks_before.extend(task_subtasks.iter().cloned());
+        let mut tasks_after = Vec::new();
+
+        // Tasks waiting on anything completed here are candidates for being unblocked
+        let mut dependents = task_repository.find_open_dependents(task.id).await?;
+        for subtask in &task_subtasks {
+            dependents.extend(task_repository.find_open_dependents(subtask.id).await?);
+        }
+
+        let updated_at = Utc::now();
+        for mut subtask in task_subtasks {
+            subtask.completed_at_utc = Some(completed_at);
+            subtask.updated_at_utc = updated_at;
+            tasks_after.push(subtask);
+        }
+
+        task.completed_at_utc = Some(completed_at);
+        task.updated_at_utc = updated_at;
+        tasks_after.push(task.clone());
+        operation.record_tasks(tasks_before, tasks_after);
+
+        // The recurrence moves to the next occurrence, or is removed once it has none
+        if let Some(recurring_task) = recurring_task_repository.find_by_task_id(task.id).await? {
+            let (next_task, recurring_task_after) =
+                RecurringTaskManager::next_occurrence(&task, recurring_task.clone())?;
+            if let Some(mut next_task) = next_task {
+                next_task.position =
+                    Self::position_at_end(&mut task_repository, operation, &next_task).await?;
+                operation.record_task(None, Some(next_task));
+            }
+            operation.record_recurring_task(Some(recurring_task), recurring_task_after);
+        }
+
+        Ok(dependents)
     }
 
     async fn unmark_task_completed(&self, task_id: Uuid) -> Result<(), TaskError> {
@@ -183,8 +505,13 @@ impl<'a> TaskManager<'a> {
             Some(task) => task,
         };
 
+        let task_before = task.clone();
         task.completed_at_utc = None;
-        repository.save(&mut task).await?;
+        task.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Reopen task");
+        operation.record_task(Some(task_before), Some(task));
+        self.commit(&operation).await?;
 
         Ok(())
     }
@@ -231,15 +558,145 @@ impl<'a> TaskManager<'a> {
             .map_err(Into::into)
     }
 
-    pub async fn move_subtasks_to_project(
+    /// Moves a task, with its subtasks, under another parent or back to the
+    /// top level when `parent_task_id` is `None`. The subtree follows the new
+    /// parent into its project.
+    pub async fn move_task_to_parent(
+        &self,
+        task_id: Uuid,
+        parent_task_id: Option<Uuid>,
+    ) -> Result<Task, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        let subtree_before = repository.find_subtree(task_id).await?;
+        let mut task = subtree_before
+            .first()
+            .cloned()
+            .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
+
+        let project_id = match parent_task_id {
+            Some(parent_task_id) => {
+                if subtree_before.iter().any(|task| task.id == parent_task_id) {
+                    return Err(Box::new(TaskError::ParentCycle));
+                }
+                let parent = repository
+                    .find_by_id(parent_task_id)
+                    .await?
+                    .filter(|parent| parent.deleted_at_utc.is_none())
+                    .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
+                parent.project_id
+            }
+            None => task.project_id,
+        };
+
+        let updated_at = Utc::now();
+        let task_before = task.clone();
+        task.parent_task_id = parent_task_id;
+        if parent_task_id.is_some() {
+            task.section_id = None;
+        }
+        if task.project_id != project_id {
+            task.project_id = project_id;
+            task.section_id = None;
+        }
+        task.updated_at_utc = updated_at;
+        // A task joining another list is placed at its end
+        if !task.shares_list_with(&task_before) {
+            task.position = repository.position_at_end(&task).await?;
+        }
+
+        let mut operation = UndoOperation::new("Move task to parent");
+        operation.record_task(Some(task_before), Some(task.clone()));
+        for subtask in subtree_before.into_iter().skip(1) {
+            if subtask.project_id != project_id {
+                let mut moved_subtask = subtask.clone();
+                moved_subtask.project_id = project_id;
+                moved_subtask.section_id = None;
+                moved_subtask.updated_at_utc = updated_at;
+                operation.record_task(Some(subtask), Some(moved_subtask));
+            }
+        }
+        self.commit(&operation).await?;
+
+        Ok(task)
+    }
+
+    /// Copies a task with its subtasks and their recurrence settings. The copy
+    /// is placed at the end of the original's list.
+    pub async fn duplicate_task(&self, task_id: Uuid) -> Result<Task, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        let mut recurring_task_repository =
+            self.repository_provider.recurring_task_repository().await?;
+
+        let subtree: Vec<Task> = repository
+            .find_subtree(task_id)
+            .await?
+            .into_iter()
+            .filter(|task| task.deleted_at_utc.is_none())
+            .collect();
+        let original = subtree
+            .first()
+            .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
+
+        let mut operation = UndoOperation::new("Duplicate task");
+        // Subtree rows come parents first, so a parent's copy is known before its subtasks
+        let mut copied_ids: HashMap<Uuid, Uuid> = HashMap::new();
+        let mut copies = Vec::new();
+        for task in &subtree {
+            if task.id != original.id
+                && !task
+                    .parent_task_id
+                    .is_some_and(|parent_task_id| copied_ids.contains_key(&parent_task_id))
+            {
+                continue;
+            }
+
+            let mut copy = task.duplicate();
+            if task.id == original.id {
+                copy.position = repository.position_at_end(&copy).await?;
+            } else {
+                copy.parent_task_id = task
+                    .parent_task_id
+                    .and_then(|parent_task_id| copied_ids.get(&parent_task_id).copied());
+            }
+            copied_ids.insert(task.id, copy.id);
+
+            operation.record_task(None, Some(copy.clone()));
+            if let Some(recurring_task) = recurring_task_repository.find_by_task_id(task.id).await?
+            {
+                let recurring_copy = RecurringTask {
+                    id: Uuid::now_v7(),
+                    task_id: copy.id,
+                    created_at_utc: copy.created_at_utc,
+                    updated_at_utc: copy.updated_at_utc,
+                    ..recurring_task
+                };
+                operation.record_recurring_task(None, Some(recurring_copy));
+            }
+            copies.push(copy);
+        }
+
+        self.commit(&operation).await?;
+
+        Ok(copies.remove(0))
+    }
+
+    pub async fn load_task_tree(&self, task_id: Uuid) -> Result<TaskTreeNode, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        let rows = repository.find_tree(task_id).await?;
+
+        Ok(TaskTreeNode::build(rows)
+            .pop()
+            .ok_or_else(|| Box::new(TaskError::TaskNotFound))?)
+    }
+
+    pub async fn load_project_task_trees(
         &self,
-        parent_task_id: Uuid,
         project_id: Uuid,
-    ) -> Result<(), sqlx::Error> {
+    ) -> Result<Vec<TaskTreeNode>, Box<dyn Error>> {
         let mut repository = self.repository_provider.task_repository().await?;
-        repository
-            .move_subtasks_to_project(parent_task_id, project_id)
-            .await
+        let rows = repository.find_trees_by_project(project_id).await?;
+
+        Ok(TaskTreeNode::build(rows))
     }
 
     pub async fn load_task(&self, task_id: Uuid) -> Result<Task, Box<dyn Error>> {
@@ -257,9 +714,202 @@ impl<'a> TaskManager<'a> {
             .await?
             .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
 
-        task.completed_at_utc = Some(Utc::now());
+        let task_before = task.clone();
+        task.archived_at_utc = Some(Utc::now());
         task.updated_at_utc = Utc::now();
-        repository.save(&mut task).await?;
+
+        let mut operation = UndoOperation::new("Archive task");
+        operation.record_task(Some(task_before), Some(task));
+        self.commit(&operation).await?;
+
         Ok(())
     }
+
+    pub async fn unarchive_task(&self, task_id: Uuid) -> Result<Task, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        let mut task = repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
+
+        if task.status() != TaskStatus::Archived {
+            return Err(Box::new(TaskError::TaskNotArchived));
+        }
+
+        let task_before = task.clone();
+        task.archived_at_utc = None;
+        task.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Unarchive task");
+        operation.record_task(Some(task_before), Some(task.clone()));
+        self.commit(&operation).await?;
+
+        Ok(task)
+    }
+
+    /// Places a task right after `before_id` and right before `after_id`, both
+    /// being its neighbours in the same project or parent task. Leaving one of
+    /// them out moves the task to the start or the end of the list.
+    pub async fn move_task(
+        &self,
+        task_id: Uuid,
+        before_id: Option<Uuid>,
+        after_id: Option<Uuid>,
+    ) -> Result<Task, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        let mut task = repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
+
+        let mut neighbours = Vec::new();
+        for neighbour_id in [before_id, after_id] {
+            let neighbour = match neighbour_id {
+                Some(neighbour_id) => Some(
+                    repository
+                        .find_by_id(neighbour_id)
+                        .await?
+                        .ok_or_else(|| Box::new(TaskError::TaskNotFound))?,
+                ),
+                None => None,
+            };
+            if let Some(neighbour) = &neighbour {
+                if !neighbour.shares_list_with(&task) {
+                    return Err(Box::new(TaskError::DifferentList));
+                }
+            }
+            neighbours.push(neighbour);
+        }
+
+        let task_before = task.clone();
+        task.position = position::rank_between(
+            neighbours[0].as_ref().map(|task| task.position.as_str()),
+            neighbours[1].as_ref().map(|task| task.position.as_str()),
+        )?;
+        task.updated_at_utc = Utc::now();
+
+        let mut operation = UndoOperation::new("Move task");
+        operation.record_task(Some(task_before), Some(task.clone()));
+        self.commit(&operation).await?;
+
+        Ok(task)
+    }
+
+    pub async fn load_archived_tasks(&self) -> Result<Vec<Task>, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        repository.find_archived().await.map_err(Into::into)
+    }
+
+    pub async fn add_dependency(
+        &self,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<(), Box<dyn Error>> {
+        if task_id == depends_on_task_id {
+            return Err(Box::new(TaskError::SelfDependency));
+        }
+
+        let mut repository = self.repository_provider.task_repository().await?;
+        repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
+        repository
+            .find_by_id(depends_on_task_id)
+            .await?
+            .ok_or_else(|| Box::new(TaskError::TaskNotFound))?;
+
+        if repository
+            .depends_on_transitively(depends_on_task_id, task_id)
+            .await?
+        {
+            return Err(Box::new(TaskError::DependencyCycle));
+        }
+
+        if !repository
+            .has_dependency(task_id, depends_on_task_id)
+            .await?
+        {
+            let mut operation = UndoOperation::new("Add dependency");
+            operation.record_dependency(task_id, depends_on_task_id, false, true);
+            self.commit(&operation).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn remove_dependency(
+        &self,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        if repository
+            .has_dependency(task_id, depends_on_task_id)
+            .await?
+        {
+            let mut operation = UndoOperation::new("Remove dependency");
+            operation.record_dependency(task_id, depends_on_task_id, true, false);
+            self.commit(&operation).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn load_dependencies(&self, task_id: Uuid) -> Result<Vec<Task>, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        repository
+            .find_dependencies(task_id)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub async fn load_actionable_tasks(&self) -> Result<Vec<Task>, Box<dyn Error>> {
+        let mut repository = self.repository_provider.task_repository().await?;
+        repository.find_actionable().await.map_err(Into::into)
+    }
+
+    pub async fn load_tasks_by_filter(
+        &self,
+        filter_expression: &str,
+        sort_order: TaskSortOrder,
+    ) -> Result<Vec<Task>, Box<dyn Error>> {
+        let expression = filter::parse(filter_expression)?;
+        let compiled_filter = filter::compile(&expression, Utc::now())?;
+
+        let mut repository = self.repository_provider.task_repository().await?;
+        repository
+            .find_by_filter(&compiled_filter, sort_order)
+            .await
+            .map_err(Into::into)
+    }
+
+    // Writes the recorded changes together with the undo operation, so a failure leaves
+    // neither the tasks nor the undo history half updated
+    async fn commit(&self, operation: &UndoOperation) -> Result<(), sqlx::Error> {
+        let mut undo_repository = self.repository_provider.undo_repository().await?;
+        undo_repository.commit(operation).await
+    }
+
+    // The end of the task's list, after the tasks the operation already places there
+    async fn position_at_end(
+        repository: &mut impl TaskRepository,
+        operation: &UndoOperation,
+        task: &Task,
+    ) -> Result<String, Box<dyn Error>> {
+        let position = repository.position_at_end(task).await?;
+        let last_recorded = operation
+            .task_changes()
+            .filter_map(|(_, after)| after)
+            .filter(|recorded| recorded.id != task.id && recorded.shares_list_with(task))
+            .map(|recorded| recorded.position.as_str())
+            .max();
+
+        match last_recorded {
+            Some(last_recorded) if last_recorded >= position.as_str() => {
+                Ok(position::rank_between(Some(last_recorded), None)?)
+            }
+            _ => Ok(position),
+        }
+    }
 }
@@ -5,17 +5,75 @@ use std::collections::HashMap;
 use std::error::Error;
 use uuid::Uuid;
 
+pub mod filter;
 pub mod manager;
+pub mod position;
 pub mod repository;
 pub mod tauri;
 mod test;
 
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    strum_macros::Display,
+    strum_macros::EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+pub enum Priority {
+    P1,
+    P2,
+    P3,
+    #[default]
+    P4,
+}
+
+impl Priority {
+    /// Numeric level stored in the database, 1 being the most urgent.
+    pub fn level(&self) -> i64 {
+        match self {
+            Priority::P1 => 1,
+            Priority::P2 => 2,
+            Priority::P3 => 3,
+            Priority::P4 => 4,
+        }
+    }
+
+    pub fn from_level(level: i64) -> Result<Self, String> {
+        match level {
+            1 => Ok(Priority::P1),
+            2 => Ok(Priority::P2),
+            3 => Ok(Priority::P3),
+            4 => Ok(Priority::P4),
+            _ => Err(format!("Invalid priority level: {}", level)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, strum_macros::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum TaskStatus {
+    Open,
+    Completed,
+    Archived,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UpdatedTaskData {
     pub title: String,
     pub description: Option<String>,
     pub project_id: Option<String>,
     pub due_date: Option<String>,
+    // Keeps the current priority when not provided
+    pub priority: Option<Priority>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -24,6 +82,7 @@ pub struct CreateTaskData {
     pub description: Option<String>,
     pub project_id: Option<String>,
     pub due_at_utc: Option<String>,
+    pub priority: Option<Priority>,
 }
 
 #[derive(Debug, FromRow)]
@@ -34,9 +93,16 @@ struct TaskRow {
     pub project_id: Option<String>,
     pub parent_task_id: Option<String>,
     pub due_at_utc: Option<String>,
+    pub priority: i64,
     pub created_at_utc: String,
     pub completed_at_utc: Option<String>,
     pub updated_at_utc: String,
+    pub deleted_at_utc: Option<String>,
+    pub archived_at_utc: Option<String>,
+    pub section_id: Option<String>,
+    pub position: String,
+    pub auto_complete: Option<bool>,
+    pub estimated_minutes: Option<i64>,
 }
 
 impl TryFrom<TaskRow> for Task {
@@ -57,6 +123,7 @@ impl TryFrom<TaskRow> for Task {
                 .map(|date| DateTime::parse_from_rfc3339(&date))
                 .transpose()?
                 .map(DateTime::<Utc>::from),
+            priority: Priority::from_level(row.priority)?,
             created_at_utc: DateTime::parse_from_rfc3339(&row.created_at_utc)?.with_timezone(&Utc),
             completed_at_utc: row
                 .completed_at_utc
@@ -64,11 +131,28 @@ impl TryFrom<TaskRow> for Task {
                 .transpose()?
                 .map(DateTime::<Utc>::from),
             updated_at_utc: DateTime::parse_from_rfc3339(&row.updated_at_utc)?.with_timezone(&Utc),
+            deleted_at_utc: row
+                .deleted_at_utc
+                .map(|date| DateTime::parse_from_rfc3339(&date))
+                .transpose()?
+                .map(DateTime::<Utc>::from),
+            archived_at_utc: row
+                .archived_at_utc
+                .map(|date| DateTime::parse_from_rfc3339(&date))
+                .transpose()?
+                .map(DateTime::<Utc>::from),
+            section_id: row.section_id.map(|id| Uuid::parse_str(&id)).transpose()?,
+            position: row.position,
+            auto_complete: row.auto_complete,
+            estimated_minutes: row.estimated_minutes,
+            blocked: false,
+            subtask_total: 0,
+            subtask_completed: 0,
         })
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Task {
     pub id: Uuid,
     pub title: String,
@@ -76,9 +160,30 @@ pub struct Task {
     pub project_id: Option<Uuid>,
     pub parent_task_id: Option<Uuid>,
     pub due_at_utc: Option<DateTime<Utc>>,
+    pub priority: Priority,
     pub created_at_utc: DateTime<Utc>,
     pub completed_at_utc: Option<DateTime<Utc>>,
     pub updated_at_utc: DateTime<Utc>,
+    // Set while the task sits in the trash
+    pub deleted_at_utc: Option<DateTime<Utc>>,
+    // Set for tasks that were put away without being done, e.g. promoted to a project
+    pub archived_at_utc: Option<DateTime<Utc>>,
+    // Section of the project the task is grouped under, cleared when it changes project
+    pub section_id: Option<Uuid>,
+    // Rank within the tasks sharing its project and parent, see `position::rank_between`
+    pub position: String,
+    // Completes the task once all its subtasks are done, None follows the global setting
+    pub auto_complete: Option<bool>,
+    // Expected time to get the task done, compared against its time entries
+    pub estimated_minutes: Option<i64>,
+    // Computed when loading: true while any task this one depends on is still open
+    #[serde(default)]
+    pub blocked: bool,
+    // Computed when loading: direct subtasks, leaving out trashed and archived ones
+    #[serde(default)]
+    pub subtask_total: i64,
+    #[serde(default)]
+    pub subtask_completed: i64,
 }
 
 impl Task {
@@ -96,11 +201,156 @@ impl Task {
             project_id,
             parent_task_id,
             due_at_utc,
+            priority: Priority::default(),
             created_at_utc: Utc::now(),
             updated_at_utc: Utc::now(),
             completed_at_utc: None,
+            deleted_at_utc: None,
+            archived_at_utc: None,
+            section_id: None,
+            // Assigned when saved, at the end of its list
+            position: String::new(),
+            auto_complete: None,
+            estimated_minutes: None,
+            blocked: false,
+            subtask_total: 0,
+            subtask_completed: 0,
+        }
+    }
+
+    // An open copy under a new id, still in the same project, parent and position
+    pub fn duplicate(&self) -> Self {
+        let now = Utc::now();
+        Task {
+            id: Uuid::now_v7(),
+            created_at_utc: now,
+            updated_at_utc: now,
+            completed_at_utc: None,
+            deleted_at_utc: None,
+            archived_at_utc: None,
+            blocked: false,
+            subtask_total: 0,
+            subtask_completed: 0,
+            ..self.clone()
+        }
+    }
+
+    // Takes over the edited fields, a task moved to another project leaves its section
+    pub fn apply_update(&mut self, data: UpdatedTaskData) -> Result<(), Box<dyn Error>> {
+        self.title = data.title;
+        self.description = data.description;
+        self.due_at_utc = data
+            .due_date
+            .map(|date| DateTime::parse_from_rfc3339(&date))
+            .transpose()?
+            .map(DateTime::<Utc>::from);
+        if let Some(priority) = data.priority {
+            self.priority = priority;
+        }
+        self.updated_at_utc = Utc::now();
+
+        let project_id = data
+            .project_id
+            .map(|project_id| Uuid::parse_str(&project_id))
+            .transpose()?;
+        // Sections belong to a single project
+        if project_id != self.project_id {
+            self.section_id = None;
+        }
+        self.project_id = project_id;
+
+        Ok(())
+    }
+
+    // Subtasks are ordered among their siblings, other tasks within their project or the inbox
+    pub fn shares_list_with(&self, other: &Task) -> bool {
+        match self.parent_task_id {
+            Some(parent_task_id) => other.parent_task_id == Some(parent_task_id),
+            None => other.parent_task_id.is_none() && other.project_id == self.project_id,
+        }
+    }
+
+    // Archiving takes precedence, an archived task was never actually done
+    pub fn status(&self) -> TaskStatus {
+        if self.archived_at_utc.is_some() {
+            TaskStatus::Archived
+        } else if self.completed_at_utc.is_some() {
+            TaskStatus::Completed
+        } else {
+            TaskStatus::Open
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskTreeNode {
+    pub task: Task,
+    // 0 for the task the tree was loaded from
+    pub depth: i64,
+    // Rollups over every descendant, not only the direct children
+    pub descendant_count: i64,
+    pub completed_descendant_count: i64,
+    pub children: Vec<TaskTreeNode>,
+}
+
+impl TaskTreeNode {
+    /// Nests the rows of a tree query, each a task with its depth, under
+    /// their parents. Rows at depth 0 become the roots, in the given order.
+    pub fn build(rows: Vec<(Task, i64)>) -> Vec<TaskTreeNode> {
+        let mut roots = Vec::new();
+        let mut children_by_parent: HashMap<Uuid, Vec<(Task, i64)>> = HashMap::new();
+        for (task, depth) in rows {
+            match task.parent_task_id {
+                Some(parent_task_id) if depth > 0 => children_by_parent
+                    .entry(parent_task_id)
+                    .or_default()
+                    .push((task, depth)),
+                _ => roots.push((task, depth)),
+            }
         }
+
+        roots
+            .into_iter()
+            .map(|(task, depth)| Self::attach_children(task, depth, &mut children_by_parent))
+            .collect()
     }
+
+    fn attach_children(
+        task: Task,
+        depth: i64,
+        children_by_parent: &mut HashMap<Uuid, Vec<(Task, i64)>>,
+    ) -> TaskTreeNode {
+        let children: Vec<TaskTreeNode> = children_by_parent
+            .remove(&task.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(child, depth)| Self::attach_children(child, depth, children_by_parent))
+            .collect();
+
+        let descendant_count = children
+            .iter()
+            .map(|child| 1 + child.descendant_count)
+            .sum();
+        let completed_descendant_count = children
+            .iter()
+            .map(|child| {
+                i64::from(child.task.completed_at_utc.is_some()) + child.completed_descendant_count
+            })
+            .sum();
+
+        TaskTreeNode {
+            task,
+            depth,
+            descendant_count,
+            completed_descendant_count,
+            children,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskCompletion {
+    pub unblocked_tasks: Vec<Task>,
 }
 
 #[derive(Debug, Serialize)]
@@ -118,7 +368,7 @@ impl PeriodTaskStatistic {
             r#"
             SELECT date(completed_at_utc) as date, COUNT(*) as count
             FROM tasks
-            WHERE completed_at_utc IS NOT NULL
+            WHERE completed_at_utc IS NOT NULL AND archived_at_utc IS NULL
             GROUP BY date(completed_at_utc)
             ORDER BY date(completed_at_utc) DESC
             "#,
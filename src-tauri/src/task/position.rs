@@ -0,0 +1,51 @@
+// Ranks are base 36 strings compared byte by byte, read as the digits of a
+// fraction between 0 and 1. There is always room for a new rank between two
+// others, so placing a task never requires renumbering its neighbours.
+const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE: usize = 36;
+
+fn digit_at(rank: &str, index: usize) -> Result<usize, String> {
+    match rank.as_bytes().get(index) {
+        Some(byte) => DIGITS
+            .iter()
+            .position(|digit| digit == byte)
+            .ok_or_else(|| format!("Invalid position: {}", rank)),
+        None => Ok(0),
+    }
+}
+
+/// Returns a rank sorting strictly after `before` and strictly before `after`.
+/// A missing bound stands for the start or the end of the list.
+pub fn rank_between(before: Option<&str>, after: Option<&str>) -> Result<String, String> {
+    let lower = before.unwrap_or("");
+    if let Some(upper) = after {
+        if lower >= upper {
+            return Err(format!("Position {} does not sort before {}", lower, upper));
+        }
+    }
+
+    let mut upper = after;
+    let mut rank = String::new();
+    let mut index = 0;
+    loop {
+        let low = digit_at(lower, index)?;
+        let high = match upper {
+            Some(upper) if index < upper.len() => digit_at(upper, index)?,
+            // An upper bound that is a prefix of the lower one cannot sort after it
+            Some(_) => return Err(format!("No position fits before {}", lower)),
+            None => BASE,
+        };
+
+        if high > low + 1 {
+            rank.push(DIGITS[(low + high) / 2] as char);
+            return Ok(rank);
+        }
+
+        rank.push(DIGITS[low] as char);
+        if high == low + 1 {
+            // Anything longer than the lower bound on this digit stays below the upper one
+            upper = None;
+        }
+        index += 1;
+    }
+}
@@ -1,10 +1,21 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::{pool::PoolConnection, Row, Sqlite};
+use sqlx::{pool::PoolConnection, Connection, Row, Sqlite};
 use uuid::Uuid;
 
+use super::filter::{CompiledFilter, FilterParameter, TaskSortOrder};
+use super::position;
+use super::Priority;
 use super::Task;
 use super::UpdatedTaskData;
+use crate::history::{self, repository::insert_events};
+
+// Task columns along with the rollups read by `row_to_task`, computed in the same query as
+// the tasks themselves
+const TASK_COLUMNS: &str = "tasks.*, \
+    EXISTS (SELECT 1 FROM task_dependencies INNER JOIN tasks AS blockers ON blockers.id = task_dependencies.depends_on_task_id WHERE task_dependencies.task_id = tasks.id AND blockers.completed_at_utc IS NULL AND blockers.deleted_at_utc IS NULL AND blockers.archived_at_utc IS NULL) AS blocked, \
+    (SELECT COUNT(*) FROM tasks AS subtasks WHERE subtasks.parent_task_id = tasks.id AND subtasks.deleted_at_utc IS NULL AND subtasks.archived_at_utc IS NULL) AS subtask_total, \
+    (SELECT COUNT(subtasks.completed_at_utc) FROM tasks AS subtasks WHERE subtasks.parent_task_id = tasks.id AND subtasks.deleted_at_utc IS NULL AND subtasks.archived_at_utc IS NULL) AS subtask_completed";
 
 #[async_trait]
 pub trait TaskRepository: Send + Sync {
@@ -26,6 +37,11 @@ pub trait TaskRepository: Send + Sync {
         project_id: Uuid,
         include_completed_tasks: bool,
     ) -> Result<Vec<Task>, sqlx::Error>;
+    async fn find_by_label(
+        &mut self,
+        label_id: Uuid,
+        include_completed_tasks: bool,
+    ) -> Result<Vec<Task>, sqlx::Error>;
     async fn find_by_parent(&mut self, parent_task_id: Uuid) -> Result<Vec<Task>, sqlx::Error>;
     async fn find_completed_by_parent(
         &mut self,
@@ -33,11 +49,50 @@ pub trait TaskRepository: Send + Sync {
     ) -> Result<Vec<Task>, sqlx::Error>;
     async fn find_due_before(&mut self, date: DateTime<Utc>) -> Result<Vec<Task>, sqlx::Error>;
     async fn find_inbox(&mut self) -> Result<Vec<Task>, sqlx::Error>;
-    async fn move_subtasks_to_project(
+    async fn find_actionable(&mut self) -> Result<Vec<Task>, sqlx::Error>;
+    async fn find_by_filter(
         &mut self,
-        parent_task_id: Uuid,
-        project_id: Uuid,
+        filter: &CompiledFilter,
+        sort_order: TaskSortOrder,
+    ) -> Result<Vec<Task>, sqlx::Error>;
+    async fn add_dependency(
+        &mut self,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<(), sqlx::Error>;
+    async fn remove_dependency(
+        &mut self,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<(), sqlx::Error>;
+    async fn has_dependency(
+        &mut self,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<bool, sqlx::Error>;
+    async fn find_dependencies(&mut self, task_id: Uuid) -> Result<Vec<Task>, sqlx::Error>;
+    async fn find_open_dependents(&mut self, task_id: Uuid) -> Result<Vec<Task>, sqlx::Error>;
+    async fn depends_on_transitively(
+        &mut self,
+        task_id: Uuid,
+        other_task_id: Uuid,
+    ) -> Result<bool, sqlx::Error>;
+    async fn soft_delete_with_subtasks(
+        &mut self,
+        task_id: Uuid,
+        deleted_at: DateTime<Utc>,
     ) -> Result<(), sqlx::Error>;
+    async fn find_deleted(&mut self) -> Result<Vec<Task>, sqlx::Error>;
+    async fn find_archived(&mut self) -> Result<Vec<Task>, sqlx::Error>;
+    async fn find_all_by_project(&mut self, project_id: Uuid) -> Result<Vec<Task>, sqlx::Error>;
+    async fn find_subtree(&mut self, task_id: Uuid) -> Result<Vec<Task>, sqlx::Error>;
+    async fn position_at_end(&mut self, task: &Task) -> Result<String, sqlx::Error>;
+    async fn find_tree(&mut self, task_id: Uuid) -> Result<Vec<(Task, i64)>, sqlx::Error>;
+    async fn find_trees_by_project(
+        &mut self,
+        project_id: Uuid,
+    ) -> Result<Vec<(Task, i64)>, sqlx::Error>;
+    async fn purge_deleted_before(&mut self, date: DateTime<Utc>) -> Result<u64, sqlx::Error>;
 }
 
 pub struct SqliteTaskRepository {
@@ -49,7 +104,64 @@ impl SqliteTaskRepository {
         Self { connection }
     }
 
-    async fn row_to_task(&mut self, row: sqlx::sqlite::SqliteRow) -> Result<Task, sqlx::Error> {
+    async fn record_subtree_changes(
+        &mut self,
+        task_id: Uuid,
+        subtree_before: Vec<Task>,
+    ) -> Result<(), sqlx::Error> {
+        let subtree_after = self.find_subtree(task_id).await?;
+
+        for task in subtree_after {
+            if let Some(previous) = subtree_before
+                .iter()
+                .find(|previous| previous.id == task.id)
+            {
+                let events = history::task_changes(Some(previous), &task);
+                insert_events(&mut self.connection, &events).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Loads the tasks matching `roots_condition` and all their live descendants, with their depth,
+    // in a single query that also selects each node's blocked and subtask rollups
+    async fn find_tree_rows(
+        &mut self,
+        roots_condition: &str,
+        root_id: Uuid,
+    ) -> Result<Vec<(Task, i64)>, sqlx::Error> {
+        let query = format!(
+            r#"
+            WITH RECURSIVE tree(id, depth) AS (
+                SELECT id, 0 FROM tasks
+                WHERE {} AND deleted_at_utc IS NULL AND archived_at_utc IS NULL
+                UNION ALL
+                SELECT tasks.id, tree.depth + 1 FROM tasks
+                INNER JOIN tree ON tasks.parent_task_id = tree.id
+                WHERE tasks.deleted_at_utc IS NULL AND tasks.archived_at_utc IS NULL
+            )
+            SELECT {}, tree.depth AS depth FROM tasks
+            INNER JOIN tree ON tree.id = tasks.id
+            ORDER BY tree.depth ASC, tasks.position ASC
+            "#,
+            roots_condition, TASK_COLUMNS
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(root_id.to_string())
+            .fetch_all(&mut *self.connection)
+            .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let depth: i64 = row.get("depth");
+                Ok((Self::row_to_task(row)?, depth))
+            })
+            .collect()
+    }
+
+    fn row_to_task(row: sqlx::sqlite::SqliteRow) -> Result<Task, sqlx::Error> {
         let id = Uuid::parse_str(row.get("id")).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
         let project_id = match row.get::<Option<String>, _>("project_id") {
             Some(id) => Some(Uuid::parse_str(&id).map_err(|e| sqlx::Error::Decode(Box::new(e)))?),
@@ -67,6 +179,8 @@ impl SqliteTaskRepository {
             ),
             None => None,
         };
+        let priority =
+            Priority::from_level(row.get("priority")).map_err(|e| sqlx::Error::Decode(e.into()))?;
         let created_at_utc = DateTime::parse_from_rfc3339(row.get("created_at_utc"))
             .map_err(|e| sqlx::Error::Decode(Box::new(e)))?
             .with_timezone(&Utc);
@@ -81,6 +195,32 @@ impl SqliteTaskRepository {
         let updated_at_utc = DateTime::parse_from_rfc3339(row.get("updated_at_utc"))
             .map_err(|e| sqlx::Error::Decode(Box::new(e)))?
             .with_timezone(&Utc);
+        let deleted_at_utc = match row.get::<Option<String>, _>("deleted_at_utc") {
+            Some(date) => Some(
+                DateTime::parse_from_rfc3339(&date)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?
+                    .with_timezone(&Utc),
+            ),
+            None => None,
+        };
+        let archived_at_utc = match row.get::<Option<String>, _>("archived_at_utc") {
+            Some(date) => Some(
+                DateTime::parse_from_rfc3339(&date)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?
+                    .with_timezone(&Utc),
+            ),
+            None => None,
+        };
+        let section_id = match row.get::<Option<String>, _>("section_id") {
+            Some(id) => Some(Uuid::parse_str(&id).map_err(|e| sqlx::Error::Decode(Box::new(e)))?),
+            None => None,
+        };
+        let position = row.get("position");
+        let auto_complete = row.get("auto_complete");
+        let estimated_minutes = row.get("estimated_minutes");
+        let blocked = row.get("blocked");
+        let subtask_total = row.get("subtask_total");
+        let subtask_completed = row.get("subtask_completed");
 
         Ok(Task {
             id,
@@ -89,9 +229,19 @@ impl SqliteTaskRepository {
             project_id,
             parent_task_id,
             due_at_utc,
+            priority,
             created_at_utc,
             completed_at_utc,
             updated_at_utc,
+            deleted_at_utc,
+            archived_at_utc,
+            section_id,
+            position,
+            auto_complete,
+            estimated_minutes,
+            blocked,
+            subtask_total,
+            subtask_completed,
         })
     }
 }
@@ -99,17 +249,21 @@ impl SqliteTaskRepository {
 #[async_trait]
 impl TaskRepository for SqliteTaskRepository {
     async fn save(&mut self, task: &mut Task) -> Result<(), sqlx::Error> {
-        let exists = sqlx::query("SELECT 1 FROM tasks WHERE id = ?1 LIMIT 1")
-            .bind(task.id.to_string())
-            .fetch_optional(&mut *self.connection)
-            .await?
-            .is_some();
+        let previous = self.find_by_id(task.id).await?;
 
         task.updated_at_utc = Utc::now();
 
-        if exists {
+        // A task joining another list is placed at its end
+        let changed_list = previous
+            .as_ref()
+            .is_some_and(|previous| !previous.shares_list_with(task));
+        if task.position.is_empty() || changed_list {
+            task.position = self.position_at_end(task).await?;
+        }
+
+        if previous.is_some() {
             sqlx::query(
-                "UPDATE tasks SET title = ?1, description = ?2, due_at_utc = ?3, parent_task_id = ?4, updated_at_utc = ?5, project_id = ?6, completed_at_utc = ?7 WHERE id = ?8"
+                "UPDATE tasks SET title = ?1, description = ?2, due_at_utc = ?3, parent_task_id = ?4, updated_at_utc = ?5, project_id = ?6, completed_at_utc = ?7, priority = ?8, archived_at_utc = ?9, section_id = ?10, position = ?11, auto_complete = ?12, estimated_minutes = ?13 WHERE id = ?14"
             )
             .bind(&task.title)
             .bind(&task.description)
@@ -118,12 +272,18 @@ impl TaskRepository for SqliteTaskRepository {
             .bind(task.updated_at_utc.to_rfc3339())
             .bind(task.project_id.map(|project_id| project_id.to_string()))
             .bind(task.completed_at_utc.map(|date| date.to_rfc3339()))
+            .bind(task.priority.level())
+            .bind(task.archived_at_utc.map(|date| date.to_rfc3339()))
+            .bind(task.section_id.map(|id| id.to_string()))
+            .bind(&task.position)
+            .bind(task.auto_complete)
+            .bind(task.estimated_minutes)
             .bind(task.id.to_string())
             .execute(&mut *self.connection)
             .await?;
         } else {
             sqlx::query(
-                "INSERT INTO tasks (id, title, description, project_id, parent_task_id, due_at_utc, created_at_utc, updated_at_utc) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"
+                "INSERT INTO tasks (id, title, description, project_id, parent_task_id, due_at_utc, created_at_utc, updated_at_utc, priority, section_id, position, auto_complete, estimated_minutes) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)"
             )
             .bind(task.id.to_string())
             .bind(&task.title)
@@ -133,30 +293,53 @@ impl TaskRepository for SqliteTaskRepository {
             .bind(task.due_at_utc.map(|date| date.to_rfc3339()))
             .bind(task.created_at_utc.to_rfc3339())
             .bind(task.updated_at_utc.to_rfc3339())
+            .bind(task.priority.level())
+            .bind(task.section_id.map(|id| id.to_string()))
+            .bind(&task.position)
+            .bind(task.auto_complete)
+            .bind(task.estimated_minutes)
             .execute(&mut *self.connection)
             .await?;
         }
 
+        let events = history::task_changes(previous.as_ref(), task);
+        insert_events(&mut self.connection, &events).await?;
+
         Ok(())
     }
 
     async fn delete(&mut self, task: &Task) -> Result<(), sqlx::Error> {
-        sqlx::query("DELETE FROM tasks WHERE id = ?1")
-            .bind(task.id.to_string())
-            .execute(&mut *self.connection)
-            .await?;
+        let mut transaction = self.connection.begin().await?;
+
+        for statement in [
+            "DELETE FROM task_labels WHERE task_id = ?1",
+            "DELETE FROM task_dependencies WHERE task_id = ?1 OR depends_on_task_id = ?1",
+            "DELETE FROM recurring_tasks WHERE task_id = ?1",
+            "DELETE FROM time_entries WHERE task_id = ?1",
+            "DELETE FROM pomodoro_sessions WHERE task_id = ?1",
+            "DELETE FROM task_events WHERE entity_type = 'task' AND entity_id = ?1",
+            "DELETE FROM tasks WHERE id = ?1",
+        ] {
+            sqlx::query(statement)
+                .bind(task.id.to_string())
+                .execute(&mut *transaction)
+                .await?;
+        }
 
-        Ok(())
+        transaction.commit().await
     }
 
     async fn find_by_id(&mut self, id: Uuid) -> Result<Option<Task>, sqlx::Error> {
-        let row = sqlx::query("SELECT * FROM tasks WHERE id = ?1 LIMIT 1")
-            .bind(id.to_string())
-            .fetch_optional(&mut *self.connection)
-            .await?;
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE id = ?1 LIMIT 1",
+            TASK_COLUMNS
+        ))
+        .bind(id.to_string())
+        .fetch_optional(&mut *self.connection)
+        .await?;
 
         match row {
-            Some(row) => Ok(Some(self.row_to_task(row).await?)),
+            Some(row) => Ok(Some(Self::row_to_task(row)?)),
             None => Ok(None),
         }
     }
@@ -165,36 +348,31 @@ impl TaskRepository for SqliteTaskRepository {
         &mut self,
         include_completed: bool,
     ) -> Result<Vec<Task>, sqlx::Error> {
-        let query = match include_completed {
-            true => "SELECT * FROM tasks ORDER BY updated_at_utc DESC",
+        let condition = match include_completed {
+            true => "deleted_at_utc IS NULL AND archived_at_utc IS NULL",
             false => {
-                "SELECT * FROM tasks WHERE completed_at_utc IS NULL ORDER BY updated_at_utc DESC"
+                "completed_at_utc IS NULL AND deleted_at_utc IS NULL AND archived_at_utc IS NULL"
             }
         };
+        let query = format!(
+            "SELECT {} FROM tasks WHERE {} ORDER BY project_id, parent_task_id, priority ASC, position ASC",
+            TASK_COLUMNS, condition
+        );
 
-        let rows = sqlx::query(query).fetch_all(&mut *self.connection).await?;
-
-        let mut tasks = Vec::new();
-        for row in rows {
-            tasks.push(self.row_to_task(row).await?);
-        }
+        let rows = sqlx::query(&query).fetch_all(&mut *self.connection).await?;
 
-        Ok(tasks)
+        rows.into_iter().map(Self::row_to_task).collect()
     }
 
     async fn find_completed(&mut self) -> Result<Vec<Task>, sqlx::Error> {
-        let rows = sqlx::query(
-            "SELECT * FROM tasks WHERE completed_at_utc IS NOT NULL ORDER BY completed_at_utc DESC",
-        )
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE completed_at_utc IS NOT NULL AND deleted_at_utc IS NULL AND archived_at_utc IS NULL ORDER BY completed_at_utc DESC",
+            TASK_COLUMNS
+        ))
         .fetch_all(&mut *self.connection)
         .await?;
 
-        let mut tasks = Vec::new();
-        for row in rows {
-            tasks.push(self.row_to_task(row).await?);
-        }
-
-        Ok(tasks)
+        rows.into_iter().map(Self::row_to_task).collect()
     }
 
     async fn find_by_project(
@@ -202,89 +380,220 @@ impl TaskRepository for SqliteTaskRepository {
         project_id: Uuid,
         include_completed_tasks: bool,
     ) -> Result<Vec<Task>, sqlx::Error> {
-        let mut query = "SELECT * FROM tasks WHERE project_id = ?1".to_string();
+        let mut query =
+            format!("SELECT {} FROM tasks WHERE project_id = ?1 AND deleted_at_utc IS NULL AND archived_at_utc IS NULL", TASK_COLUMNS);
         if !include_completed_tasks {
             query += " AND completed_at_utc IS NULL";
         }
-        query += " ORDER BY updated_at_utc DESC";
+        query += " ORDER BY parent_task_id, priority ASC, position ASC";
 
         let rows = sqlx::query(&query)
             .bind(project_id.to_string())
             .fetch_all(&mut *self.connection)
             .await?;
 
-        let mut tasks = Vec::new();
-        for row in rows {
-            tasks.push(self.row_to_task(row).await?);
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn find_by_label(
+        &mut self,
+        label_id: Uuid,
+        include_completed_tasks: bool,
+    ) -> Result<Vec<Task>, sqlx::Error> {
+        let mut query = format!("SELECT {} FROM tasks INNER JOIN task_labels ON task_labels.task_id = tasks.id WHERE task_labels.label_id = ?1 AND tasks.deleted_at_utc IS NULL AND tasks.archived_at_utc IS NULL", TASK_COLUMNS);
+        if !include_completed_tasks {
+            query += " AND tasks.completed_at_utc IS NULL";
         }
+        query += " ORDER BY tasks.project_id, tasks.parent_task_id, tasks.position ASC";
+
+        let rows = sqlx::query(&query)
+            .bind(label_id.to_string())
+            .fetch_all(&mut *self.connection)
+            .await?;
 
-        Ok(tasks)
+        rows.into_iter().map(Self::row_to_task).collect()
     }
 
     async fn find_by_parent(&mut self, parent_task_id: Uuid) -> Result<Vec<Task>, sqlx::Error> {
-        let rows = sqlx::query(
-            "SELECT * FROM tasks WHERE parent_task_id = ?1 AND completed_at_utc IS NULL ORDER BY updated_at_utc DESC",
-        )
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE parent_task_id = ?1 AND completed_at_utc IS NULL AND deleted_at_utc IS NULL AND archived_at_utc IS NULL ORDER BY position ASC",
+            TASK_COLUMNS
+        ))
         .bind(parent_task_id.to_string())
         .fetch_all(&mut *self.connection)
         .await?;
 
-        let mut tasks = Vec::new();
-        for row in rows {
-            tasks.push(self.row_to_task(row).await?);
-        }
-
-        Ok(tasks)
+        rows.into_iter().map(Self::row_to_task).collect()
     }
 
     async fn find_completed_by_parent(
         &mut self,
         parent_task_id: Uuid,
     ) -> Result<Vec<Task>, sqlx::Error> {
-        let rows = sqlx::query(
-            "SELECT * FROM tasks WHERE parent_task_id = ?1 AND completed_at_utc IS NOT NULL ORDER BY completed_at_utc DESC",
-        )
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE parent_task_id = ?1 AND completed_at_utc IS NOT NULL AND deleted_at_utc IS NULL AND archived_at_utc IS NULL ORDER BY completed_at_utc DESC",
+            TASK_COLUMNS
+        ))
         .bind(parent_task_id.to_string())
         .fetch_all(&mut *self.connection)
         .await?;
 
-        let mut tasks = Vec::new();
-        for row in rows {
-            tasks.push(self.row_to_task(row).await?);
-        }
-
-        Ok(tasks)
+        rows.into_iter().map(Self::row_to_task).collect()
     }
 
     async fn find_due_before(&mut self, date: DateTime<Utc>) -> Result<Vec<Task>, sqlx::Error> {
-        let rows = sqlx::query(
-            "SELECT * FROM tasks WHERE due_at_utc < ?1 AND completed_at_utc IS NULL ORDER BY due_at_utc ASC"
-        )
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE due_at_utc < ?1 AND completed_at_utc IS NULL AND deleted_at_utc IS NULL AND archived_at_utc IS NULL ORDER BY due_at_utc ASC",
+            TASK_COLUMNS
+        ))
         .bind(date.to_rfc3339())
         .fetch_all(&mut *self.connection)
         .await?;
 
-        let mut tasks = Vec::new();
-        for row in rows {
-            tasks.push(self.row_to_task(row).await?);
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn find_inbox(&mut self) -> Result<Vec<Task>, sqlx::Error> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE project_id IS NULL AND completed_at_utc IS NULL AND deleted_at_utc IS NULL AND archived_at_utc IS NULL ORDER BY parent_task_id, priority ASC, position ASC",
+            TASK_COLUMNS
+        ))
+        .fetch_all(&mut *self.connection)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn find_actionable(&mut self) -> Result<Vec<Task>, sqlx::Error> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE completed_at_utc IS NULL AND deleted_at_utc IS NULL AND archived_at_utc IS NULL AND NOT EXISTS (SELECT 1 FROM task_dependencies INNER JOIN tasks AS blockers ON blockers.id = task_dependencies.depends_on_task_id WHERE task_dependencies.task_id = tasks.id AND blockers.completed_at_utc IS NULL AND blockers.deleted_at_utc IS NULL AND blockers.archived_at_utc IS NULL) ORDER BY project_id, parent_task_id, position ASC",
+            TASK_COLUMNS
+        ))
+        .fetch_all(&mut *self.connection)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn find_by_filter(
+        &mut self,
+        filter: &CompiledFilter,
+        sort_order: TaskSortOrder,
+    ) -> Result<Vec<Task>, sqlx::Error> {
+        let query = format!(
+            "SELECT {} FROM tasks WHERE deleted_at_utc IS NULL AND archived_at_utc IS NULL AND ({}) ORDER BY {}",
+            TASK_COLUMNS,
+            filter.where_clause,
+            sort_order.order_by_clause()
+        );
+
+        let mut sqlx_query = sqlx::query(&query);
+        for parameter in &filter.parameters {
+            sqlx_query = match parameter {
+                FilterParameter::Text(value) => sqlx_query.bind(value.clone()),
+                FilterParameter::Integer(value) => sqlx_query.bind(*value),
+            };
         }
 
-        Ok(tasks)
+        let rows = sqlx_query.fetch_all(&mut *self.connection).await?;
+
+        rows.into_iter().map(Self::row_to_task).collect()
     }
 
-    async fn find_inbox(&mut self) -> Result<Vec<Task>, sqlx::Error> {
-        let rows = sqlx::query(
-            "SELECT * FROM tasks WHERE project_id IS NULL AND completed_at_utc IS NULL ORDER BY created_at_utc DESC"
+    async fn add_dependency(
+        &mut self,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT OR IGNORE INTO task_dependencies (task_id, depends_on_task_id, created_at_utc) VALUES (?1, ?2, ?3)",
         )
+        .bind(task_id.to_string())
+        .bind(depends_on_task_id.to_string())
+        .bind(Utc::now().to_rfc3339())
+        .execute(&mut *self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn remove_dependency(
+        &mut self,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM task_dependencies WHERE task_id = ?1 AND depends_on_task_id = ?2")
+            .bind(task_id.to_string())
+            .bind(depends_on_task_id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn has_dependency(
+        &mut self,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let row = sqlx::query(
+            "SELECT EXISTS(SELECT 1 FROM task_dependencies WHERE task_id = ?1 AND depends_on_task_id = ?2) AS found",
+        )
+        .bind(task_id.to_string())
+        .bind(depends_on_task_id.to_string())
+        .fetch_one(&mut *self.connection)
+        .await?;
+
+        Ok(row.get("found"))
+    }
+
+    async fn find_dependencies(&mut self, task_id: Uuid) -> Result<Vec<Task>, sqlx::Error> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks INNER JOIN task_dependencies ON task_dependencies.depends_on_task_id = tasks.id WHERE task_dependencies.task_id = ?1 AND tasks.deleted_at_utc IS NULL AND tasks.archived_at_utc IS NULL ORDER BY tasks.project_id, tasks.parent_task_id, tasks.position ASC",
+            TASK_COLUMNS
+        ))
+        .bind(task_id.to_string())
         .fetch_all(&mut *self.connection)
         .await?;
 
-        let mut tasks = Vec::new();
-        for row in rows {
-            tasks.push(self.row_to_task(row).await?);
-        }
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn find_open_dependents(&mut self, task_id: Uuid) -> Result<Vec<Task>, sqlx::Error> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks INNER JOIN task_dependencies ON task_dependencies.task_id = tasks.id WHERE task_dependencies.depends_on_task_id = ?1 AND tasks.completed_at_utc IS NULL AND tasks.deleted_at_utc IS NULL AND tasks.archived_at_utc IS NULL ORDER BY tasks.project_id, tasks.parent_task_id, tasks.position ASC",
+            TASK_COLUMNS
+        ))
+        .bind(task_id.to_string())
+        .fetch_all(&mut *self.connection)
+        .await?;
 
-        Ok(tasks)
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn depends_on_transitively(
+        &mut self,
+        task_id: Uuid,
+        other_task_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let row = sqlx::query(
+            r#"
+            WITH RECURSIVE dependency_chain(id) AS (
+                SELECT depends_on_task_id FROM task_dependencies WHERE task_id = ?1
+                UNION
+                SELECT task_dependencies.depends_on_task_id
+                FROM task_dependencies
+                INNER JOIN dependency_chain ON task_dependencies.task_id = dependency_chain.id
+            )
+            SELECT 1 FROM dependency_chain WHERE id = ?2 LIMIT 1
+            "#,
+        )
+        .bind(task_id.to_string())
+        .bind(other_task_id.to_string())
+        .fetch_optional(&mut *self.connection)
+        .await?;
+
+        Ok(row.is_some())
     }
 
     async fn update_task(
@@ -292,37 +601,163 @@ impl TaskRepository for SqliteTaskRepository {
         task: &mut Task,
         data: UpdatedTaskData,
     ) -> Result<(), sqlx::Error> {
-        task.title = data.title;
-        task.description = data.description;
-        task.due_at_utc = data
-            .due_date
-            .map(|date| DateTime::<Utc>::from(DateTime::parse_from_rfc3339(&date).unwrap()));
-        task.updated_at_utc = Utc::now();
-
-        if let Some(project_id) = data.project_id {
-            let project_uuid =
-                Uuid::parse_str(&project_id).map_err(|e| sqlx::Error::Protocol(e.to_string()))?;
-            task.project_id = Some(project_uuid);
-        } else {
-            task.project_id = None;
-        }
+        task.apply_update(data)
+            .map_err(|e| sqlx::Error::Protocol(e.to_string()))?;
 
         self.save(task).await
     }
 
-    async fn move_subtasks_to_project(
+    async fn soft_delete_with_subtasks(
         &mut self,
-        parent_task_id: Uuid,
-        project_id: Uuid,
+        task_id: Uuid,
+        deleted_at: DateTime<Utc>,
     ) -> Result<(), sqlx::Error> {
+        let subtree_before = self.find_subtree(task_id).await?;
+
+        // Subtasks already in the trash keep their own timestamp so they are restored separately
         sqlx::query(
-            "UPDATE tasks SET project_id = ?1, parent_task_id = NULL WHERE parent_task_id = ?2",
+            r#"
+            WITH RECURSIVE subtree(id) AS (
+                SELECT id FROM tasks WHERE id = ?1
+                UNION ALL
+                SELECT tasks.id FROM tasks
+                INNER JOIN subtree ON tasks.parent_task_id = subtree.id
+                WHERE tasks.deleted_at_utc IS NULL
+            )
+            UPDATE tasks SET deleted_at_utc = ?2 WHERE id IN subtree
+            "#,
         )
-        .bind(project_id.to_string())
-        .bind(parent_task_id.to_string())
+        .bind(task_id.to_string())
+        .bind(deleted_at.to_rfc3339())
         .execute(&mut *self.connection)
         .await?;
 
-        Ok(())
+        self.record_subtree_changes(task_id, subtree_before).await
+    }
+
+    async fn find_deleted(&mut self) -> Result<Vec<Task>, sqlx::Error> {
+        // Subtasks trashed together with their parent are listed through the parent
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE deleted_at_utc IS NOT NULL AND NOT EXISTS (SELECT 1 FROM tasks AS parents WHERE parents.id = tasks.parent_task_id AND parents.deleted_at_utc = tasks.deleted_at_utc) ORDER BY deleted_at_utc DESC",
+            TASK_COLUMNS
+        ))
+        .fetch_all(&mut *self.connection)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn find_archived(&mut self) -> Result<Vec<Task>, sqlx::Error> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE archived_at_utc IS NOT NULL AND deleted_at_utc IS NULL ORDER BY archived_at_utc DESC",
+            TASK_COLUMNS
+        ))
+        .fetch_all(&mut *self.connection)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn find_all_by_project(&mut self, project_id: Uuid) -> Result<Vec<Task>, sqlx::Error> {
+        // Unlike find_by_project this includes archived and trashed tasks
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM tasks WHERE project_id = ?1",
+            TASK_COLUMNS
+        ))
+        .bind(project_id.to_string())
+        .fetch_all(&mut *self.connection)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn find_subtree(&mut self, task_id: Uuid) -> Result<Vec<Task>, sqlx::Error> {
+        let rows = sqlx::query(&format!(
+            r#"
+            WITH RECURSIVE subtree(id, depth) AS (
+                SELECT id, 0 FROM tasks WHERE id = ?1
+                UNION ALL
+                SELECT tasks.id, subtree.depth + 1 FROM tasks
+                INNER JOIN subtree ON tasks.parent_task_id = subtree.id
+            )
+            SELECT {} FROM tasks
+            INNER JOIN subtree ON subtree.id = tasks.id
+            ORDER BY subtree.depth ASC, tasks.id ASC
+            "#,
+            TASK_COLUMNS
+        ))
+        .bind(task_id.to_string())
+        .fetch_all(&mut *self.connection)
+        .await?;
+
+        rows.into_iter().map(Self::row_to_task).collect()
+    }
+
+    async fn purge_deleted_before(&mut self, date: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+        let date = date.to_rfc3339();
+        let mut transaction = self.connection.begin().await?;
+
+        // Everything attached to the purged tasks goes with them
+        for statement in [
+            "DELETE FROM task_labels WHERE task_id IN (SELECT id FROM tasks WHERE deleted_at_utc <= ?1)",
+            "DELETE FROM task_dependencies WHERE task_id IN (SELECT id FROM tasks WHERE deleted_at_utc <= ?1) OR depends_on_task_id IN (SELECT id FROM tasks WHERE deleted_at_utc <= ?1)",
+            "DELETE FROM recurring_tasks WHERE task_id IN (SELECT id FROM tasks WHERE deleted_at_utc <= ?1)",
+            "DELETE FROM time_entries WHERE task_id IN (SELECT id FROM tasks WHERE deleted_at_utc <= ?1)",
+            "DELETE FROM pomodoro_sessions WHERE task_id IN (SELECT id FROM tasks WHERE deleted_at_utc <= ?1)",
+            "DELETE FROM task_events WHERE entity_type = 'task' AND entity_id IN (SELECT id FROM tasks WHERE deleted_at_utc <= ?1)",
+        ] {
+            sqlx::query(statement)
+                .bind(&date)
+                .execute(&mut *transaction)
+                .await?;
+        }
+
+        let result = sqlx::query("DELETE FROM tasks WHERE deleted_at_utc <= ?1")
+            .bind(&date)
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_tree(&mut self, task_id: Uuid) -> Result<Vec<(Task, i64)>, sqlx::Error> {
+        self.find_tree_rows("id = ?1", task_id).await
+    }
+
+    async fn find_trees_by_project(
+        &mut self,
+        project_id: Uuid,
+    ) -> Result<Vec<(Task, i64)>, sqlx::Error> {
+        self.find_tree_rows("project_id = ?1 AND parent_task_id IS NULL", project_id)
+            .await
+    }
+
+    async fn position_at_end(&mut self, task: &Task) -> Result<String, sqlx::Error> {
+        let row = match task.parent_task_id {
+            Some(parent_task_id) => {
+                sqlx::query(
+                    "SELECT MAX(position) AS position FROM tasks WHERE parent_task_id = ?1 AND id != ?2",
+                )
+                .bind(parent_task_id.to_string())
+                .bind(task.id.to_string())
+                .fetch_one(&mut *self.connection)
+                .await?
+            }
+            None => {
+                sqlx::query(
+                    "SELECT MAX(position) AS position FROM tasks WHERE project_id IS ?1 AND parent_task_id IS NULL AND id != ?2",
+                )
+                .bind(task.project_id.map(|id| id.to_string()))
+                .bind(task.id.to_string())
+                .fetch_one(&mut *self.connection)
+                .await?
+            }
+        };
+        let last_position: Option<String> = row.get("position");
+
+        position::rank_between(last_position.as_deref().filter(|p| !p.is_empty()), None)
+            .map_err(sqlx::Error::Protocol)
     }
 }
@@ -1,11 +1,13 @@
+use tauri::async_runtime::Mutex;
 use tauri::State;
 use uuid::Uuid;
 
+use crate::configuration::Configuration;
 use crate::errors::handle_error;
 use crate::project::manager::ProjectsManager;
 use crate::repository::RepositoryProvider;
 use crate::task::manager::TaskManager;
-use crate::task::{CreateTaskData, UpdatedTaskData};
+use crate::task::{CreateTaskData, Priority, UpdatedTaskData};
 
 #[tauri::command]
 pub async fn create_task_command(
@@ -13,13 +15,20 @@ pub async fn create_task_command(
     description: Option<String>,
     due_date: Option<String>,
     project_id: Option<String>,
+    priority: Option<String>,
     repository_provider: State<'_, RepositoryProvider>,
 ) -> Result<String, String> {
+    let priority = priority
+        .map(|priority| priority.parse::<Priority>())
+        .transpose()
+        .map_err(|e| handle_error(&e))?;
+
     let create_task_data = CreateTaskData {
         title,
         description,
         due_at_utc: due_date,
         project_id,
+        priority,
     };
 
     log::debug!("Running update task command for: | {:?}", create_task_data);
@@ -41,13 +50,20 @@ pub async fn update_task_command(
     description: Option<String>,
     due_date: Option<String>,
     project_id: Option<String>,
+    priority: Option<String>,
     repository_provider: State<'_, RepositoryProvider>,
 ) -> Result<String, String> {
+    let priority = priority
+        .map(|priority| priority.parse::<Priority>())
+        .transpose()
+        .map_err(|e| handle_error(&e))?;
+
     let updated_task_data = UpdatedTaskData {
         title,
         description,
         due_date,
         project_id,
+        priority,
     };
 
     log::debug!(
@@ -86,22 +102,201 @@ pub async fn delete_task_command(
     Ok(format!("Task with ID {} deleted successfully", &task_id))
 }
 
+#[tauri::command]
+pub async fn restore_task_command(
+    task_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running restore task command for card ID: {}", task_id);
+
+    let task_manager = TaskManager::new(&repository_provider);
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let task = task_manager
+        .restore_task(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&task).unwrap())
+}
+
+#[tauri::command]
+pub async fn unarchive_task_command(
+    task_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running unarchive task command for card ID: {}", task_id);
+
+    let task_manager = TaskManager::new(&repository_provider);
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let task = task_manager
+        .unarchive_task(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&task).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn move_task_command(
+    task_id: String,
+    before_id: Option<String>,
+    after_id: Option<String>,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running move task command for card ID: {} | {:?} | {:?}",
+        task_id,
+        before_id,
+        after_id
+    );
+
+    let task_manager = TaskManager::new(&repository_provider);
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let before_uuid = before_id
+        .map(|id| Uuid::parse_str(&id))
+        .transpose()
+        .map_err(|e| handle_error(&e))?;
+    let after_uuid = after_id
+        .map(|id| Uuid::parse_str(&id))
+        .transpose()
+        .map_err(|e| handle_error(&e))?;
+
+    let task = task_manager
+        .move_task(task_uuid, before_uuid, after_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&task).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn move_task_to_parent_command(
+    task_id: String,
+    parent_task_id: Option<String>,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running move task to parent command for card ID: {} | {:?}",
+        task_id,
+        parent_task_id
+    );
+
+    let task_manager = TaskManager::new(&repository_provider);
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let parent_task_uuid = parent_task_id
+        .map(|id| Uuid::parse_str(&id))
+        .transpose()
+        .map_err(|e| handle_error(&e))?;
+
+    let task = task_manager
+        .move_task_to_parent(task_uuid, parent_task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&task).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn set_task_auto_complete_command(
+    task_id: String,
+    auto_complete: Option<bool>,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running set task auto complete command for card ID: {} | {:?}",
+        task_id,
+        auto_complete
+    );
+
+    let task_manager = TaskManager::new(&repository_provider);
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let task = task_manager
+        .set_auto_complete(task_uuid, auto_complete)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&task).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn set_task_estimate_command(
+    task_id: String,
+    estimated_minutes: Option<i64>,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running set task estimate command for card ID: {} | {:?}",
+        task_id,
+        estimated_minutes
+    );
+
+    let task_manager = TaskManager::new(&repository_provider);
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let task = task_manager
+        .set_estimate(task_uuid, estimated_minutes)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&task).unwrap())
+}
+
+#[tauri::command]
+pub async fn duplicate_task_command(
+    task_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running duplicate task command for card ID: {}", task_id);
+
+    let task_manager = TaskManager::new(&repository_provider);
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let task = task_manager
+        .duplicate_task(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&task).unwrap())
+}
+
+#[tauri::command]
+pub async fn empty_trash_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running empty trash command");
+
+    let task_manager = TaskManager::new(&repository_provider);
+
+    let purged_tasks = task_manager
+        .empty_trash()
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&purged_tasks).unwrap())
+}
+
 #[tauri::command]
 pub async fn complete_task_command(
     task_id: String,
     repository_provider: State<'_, RepositoryProvider>,
+    configuration: State<'_, Mutex<Configuration>>,
 ) -> Result<String, String> {
     log::debug!("Running complete task command for card ID: {}", task_id);
     let uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let auto_complete_parents = configuration.lock().await.auto_complete_parent_tasks;
 
-    let manager = TaskManager::new(&repository_provider);
+    let manager =
+        TaskManager::new(&repository_provider).with_auto_complete_parents(auto_complete_parents);
 
-    manager
+    let completion = manager
         .complete_task(uuid)
         .await
         .map_err(|e| handle_error(&*e))?;
 
-    Ok("{}".to_string())
+    Ok(serde_json::to_string(&completion).unwrap())
 }
 
 #[tauri::command]
@@ -110,9 +305,14 @@ pub async fn create_subtask_for_task_command(
     title: String,
     description: Option<String>,
     due_date: Option<String>,
+    priority: Option<String>,
     repository_provider: State<'_, RepositoryProvider>,
 ) -> Result<String, String> {
     let parent_task_id_uuid = Uuid::parse_str(&parent_task_id).map_err(|e| handle_error(&e))?;
+    let priority = priority
+        .map(|priority| priority.parse::<Priority>())
+        .transpose()
+        .map_err(|e| handle_error(&e))?;
 
     let task_manager = TaskManager::new(&repository_provider);
 
@@ -127,6 +327,7 @@ pub async fn create_subtask_for_task_command(
         description,
         due_at_utc: due_date,
         project_id: parent_task.project_id.map(|id| id.to_string()),
+        priority,
     };
 
     let subtask = task_manager
@@ -143,11 +344,6 @@ pub async fn promote_task_to_project_command(
     repository_provider: State<'_, RepositoryProvider>,
 ) -> Result<String, String> {
     let task_id = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
-    let task_manager = TaskManager::new(&repository_provider);
-    let task = task_manager
-        .load_task(task_id)
-        .await
-        .map_err(|e| handle_error(&*e))?;
 
     let mut project_repository = repository_provider
         .inner()
@@ -159,22 +355,79 @@ pub async fn promote_task_to_project_command(
         .task_repository()
         .await
         .map_err(|e| handle_error(&e))?;
-    let mut projects_manager = ProjectsManager::new(&mut project_repository, &mut task_repository);
+    let mut undo_repository = repository_provider
+        .inner()
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
 
     let project = projects_manager
-        .create_project(task.title.clone(), None, None, task.description.clone())
+        .promote_task_to_project(task_id)
         .await
         .map_err(|e| handle_error(&*e))?;
 
+    Ok(serde_json::to_string(&project).unwrap())
+}
+
+#[tauri::command]
+pub async fn add_task_dependency_command(
+    task_id: String,
+    depends_on_task_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running add task dependency command: {} depends on {}",
+        task_id,
+        depends_on_task_id
+    );
+
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let depends_on_uuid = Uuid::parse_str(&depends_on_task_id).map_err(|e| handle_error(&e))?;
+    let task_manager = TaskManager::new(&repository_provider);
+
     task_manager
-        .move_subtasks_to_project(task_id, project.id)
+        .add_dependency(task_uuid, depends_on_uuid)
         .await
-        .map_err(|e| handle_error(&e))?;
+        .map_err(|e| handle_error(&*e))?;
+
+    let dependencies = task_manager
+        .load_dependencies(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&dependencies).unwrap())
+}
+
+#[tauri::command]
+pub async fn remove_task_dependency_command(
+    task_id: String,
+    depends_on_task_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running remove task dependency command: {} no longer depends on {}",
+        task_id,
+        depends_on_task_id
+    );
+
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let depends_on_uuid = Uuid::parse_str(&depends_on_task_id).map_err(|e| handle_error(&e))?;
+    let task_manager = TaskManager::new(&repository_provider);
 
     task_manager
-        .archive_task(task_id)
+        .remove_dependency(task_uuid, depends_on_uuid)
         .await
         .map_err(|e| handle_error(&*e))?;
 
-    Ok(serde_json::to_string(&project).unwrap())
+    let dependencies = task_manager
+        .load_dependencies(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&dependencies).unwrap())
 }
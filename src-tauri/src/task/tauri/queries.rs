@@ -1,4 +1,5 @@
 use crate::repository::RepositoryProvider;
+use crate::task::filter::TaskSortOrder;
 use crate::task::manager::TaskManager;
 use tauri::State;
 use uuid::Uuid;
@@ -23,6 +24,19 @@ pub async fn load_task_activity_statistics_command(
     Ok(serde_json::to_string(&statistics).unwrap())
 }
 
+#[tauri::command]
+pub async fn load_trash_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load trash command");
+
+    let manager = TaskManager::new(&repository_provider);
+
+    let tasks = manager.load_trash().await.map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&tasks).unwrap())
+}
+
 #[tauri::command]
 pub async fn load_tasks_inbox_command(
     repository_provider: State<'_, RepositoryProvider>,
@@ -88,6 +102,45 @@ pub async fn load_subtasks_for_task_command(
     Ok(serde_json::to_string(&subtasks).unwrap())
 }
 
+#[tauri::command]
+pub async fn load_task_tree_command(
+    task_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load task tree command for card ID: {}", task_id);
+
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let task_manager = TaskManager::new(&repository_provider);
+
+    let tree = task_manager
+        .load_task_tree(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&tree).unwrap())
+}
+
+#[tauri::command]
+pub async fn load_project_task_trees_command(
+    project_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running load project task trees command for: {}",
+        project_id
+    );
+
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
+    let task_manager = TaskManager::new(&repository_provider);
+
+    let trees = task_manager
+        .load_project_task_trees(project_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&trees).unwrap())
+}
+
 #[tauri::command]
 pub async fn load_completed_subtasks_for_task_command(
     parent_task_id: String,
@@ -136,6 +189,22 @@ pub async fn load_completed_tasks_command(
     Ok(serde_json::to_string(&tasks).unwrap())
 }
 
+#[tauri::command]
+pub async fn load_archived_tasks_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load archived tasks command");
+
+    let manager = TaskManager::new(&repository_provider);
+
+    let tasks = manager
+        .load_archived_tasks()
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&tasks).unwrap())
+}
+
 #[tauri::command(rename_all = "camelCase")]
 pub async fn load_tasks_by_project_command(
     repository_provider: State<'_, RepositoryProvider>,
@@ -158,3 +227,63 @@ pub async fn load_tasks_by_project_command(
 
     Ok(serde_json::to_string(&tasks).unwrap())
 }
+
+#[tauri::command]
+pub async fn load_task_dependencies_command(
+    task_id: String,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+    let manager = TaskManager::new(&repository_provider);
+
+    let dependencies = manager
+        .load_dependencies(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&dependencies).unwrap())
+}
+
+#[tauri::command]
+pub async fn load_actionable_tasks_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load actionable tasks command");
+
+    let manager = TaskManager::new(&repository_provider);
+
+    let tasks = manager
+        .load_actionable_tasks()
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&tasks).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn load_tasks_by_filter_command(
+    filter: String,
+    sort_order: Option<String>,
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running load tasks by filter command - filter: {:?}, sort_order: {:?}",
+        filter,
+        sort_order
+    );
+
+    let sort_order: TaskSortOrder = sort_order
+        .map(|sort_order| sort_order.parse())
+        .transpose()
+        .map_err(|e| handle_error(&e))?
+        .unwrap_or_default();
+
+    let manager = TaskManager::new(&repository_provider);
+
+    let tasks = manager
+        .load_tasks_by_filter(&filter, sort_order)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&tasks).unwrap())
+}
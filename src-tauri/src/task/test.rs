@@ -1,9 +1,20 @@
 #[cfg(test)]
 mod task_tests {
     use super::super::manager::TaskManager;
+    use crate::history::repository::HistoryRepository;
+    use crate::history::HistoryEntityType;
+    use crate::pomodoro::repository::PomodoroSessionRepository;
+    use crate::pomodoro::PomodoroSession;
+    use crate::project::manager::ProjectsManager;
+    use crate::recurring_task::manager::RecurringTaskManager;
+    use crate::recurring_task::repository::RecurringTaskRepository;
+    use crate::recurring_task::Frequency;
     use crate::repository::RepositoryProvider;
+    use crate::task::position::rank_between;
     use crate::task::repository::TaskRepository;
-    use crate::task::{CreateTaskData, UpdatedTaskData};
+    use crate::task::{CreateTaskData, Priority, TaskStatus, UpdatedTaskData};
+    use crate::undo::manager::UndoManager;
+    use chrono::{Duration, Utc};
 
     use sqlx::migrate::MigrateDatabase;
     use sqlx::sqlite::SqlitePool;
@@ -24,6 +35,16 @@ mod task_tests {
         Ok(RepositoryProvider::new(pool))
     }
 
+    fn task_data(title: &str) -> CreateTaskData {
+        CreateTaskData {
+            title: title.to_string(),
+            description: None,
+            project_id: None,
+            due_at_utc: None,
+            priority: None,
+        }
+    }
+
     #[tokio::test]
     async fn test_task_save_and_load() {
         let provider = setup_test_db().await.unwrap();
@@ -35,6 +56,7 @@ mod task_tests {
                 description: None,
                 project_id: None,
                 due_at_utc: None,
+                priority: None,
             })
             .await
             .unwrap();
@@ -59,6 +81,7 @@ mod task_tests {
                 description: None,
                 project_id: None,
                 due_at_utc: None,
+                priority: None,
             })
             .await
             .unwrap();
@@ -68,6 +91,7 @@ mod task_tests {
             description: None,
             project_id: None,
             due_date: None,
+            priority: None,
         };
 
         let updated_task = manager
@@ -90,6 +114,7 @@ mod task_tests {
                 description: None,
                 project_id: None,
                 due_at_utc: None,
+                priority: None,
             })
             .await
             .unwrap();
@@ -104,6 +129,7 @@ mod task_tests {
                     description: None,
                     project_id: None,
                     due_at_utc: None,
+                    priority: None,
                 },
             )
             .await
@@ -127,6 +153,7 @@ mod task_tests {
                 description: None,
                 project_id: None,
                 due_at_utc: None,
+                priority: None,
             })
             .await
             .unwrap();
@@ -148,6 +175,7 @@ mod task_tests {
                 description: None,
                 project_id: None,
                 due_at_utc: None,
+                priority: None,
             })
             .await
             .unwrap();
@@ -162,6 +190,7 @@ mod task_tests {
                     description: None,
                     project_id: None,
                     due_at_utc: None,
+                    priority: None,
                 },
             )
             .await
@@ -173,4 +202,620 @@ mod task_tests {
 
         assert!(reloaded_subtask.completed_at_utc.is_some());
     }
+
+    #[tokio::test]
+    async fn it_creates_a_task_with_a_priority_and_keeps_it_on_update() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let new_task = manager
+            .create_task(CreateTaskData {
+                title: "Urgent Task".to_string(),
+                description: None,
+                project_id: None,
+                due_at_utc: None,
+                priority: Some(Priority::P1),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(Priority::P1, new_task.priority);
+
+        let updated_task = manager
+            .update_task(
+                new_task.id,
+                UpdatedTaskData {
+                    title: "Still Urgent Task".to_string(),
+                    description: None,
+                    project_id: None,
+                    due_date: None,
+                    priority: None,
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(Priority::P1, updated_task.priority);
+
+        let loaded_task = manager.load_by_id(new_task.id).await.unwrap().unwrap();
+        assert_eq!(Priority::P1, loaded_task.priority);
+    }
+
+    #[tokio::test]
+    async fn inbox_tasks_are_sorted_by_priority() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        for (title, priority) in [
+            ("Low", Priority::P4),
+            ("Urgent", Priority::P1),
+            ("Medium", Priority::P3),
+        ] {
+            manager
+                .create_task(CreateTaskData {
+                    title: title.to_string(),
+                    description: None,
+                    project_id: None,
+                    due_at_utc: None,
+                    priority: Some(priority),
+                })
+                .await
+                .unwrap();
+        }
+
+        let inbox = manager.load_inbox().await.unwrap();
+        let titles: Vec<&str> = inbox.iter().map(|task| task.title.as_str()).collect();
+
+        assert_eq!(vec!["Urgent", "Medium", "Low"], titles);
+    }
+
+    #[tokio::test]
+    async fn it_rejects_dependency_cycles() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let first = manager.create_task(task_data("First")).await.unwrap();
+        let second = manager.create_task(task_data("Second")).await.unwrap();
+        let third = manager.create_task(task_data("Third")).await.unwrap();
+
+        assert!(manager.add_dependency(first.id, first.id).await.is_err());
+
+        manager.add_dependency(second.id, first.id).await.unwrap();
+        manager.add_dependency(third.id, second.id).await.unwrap();
+
+        assert!(manager.add_dependency(first.id, second.id).await.is_err());
+        assert!(manager.add_dependency(first.id, third.id).await.is_err());
+
+        let dependencies = manager.load_dependencies(third.id).await.unwrap();
+        assert_eq!(1, dependencies.len());
+        assert_eq!(second.id, dependencies[0].id);
+    }
+
+    #[tokio::test]
+    async fn blocked_tasks_are_flagged_and_excluded_from_actionable_tasks() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let blocker = manager.create_task(task_data("Blocker")).await.unwrap();
+        let blocked = manager.create_task(task_data("Blocked")).await.unwrap();
+
+        manager
+            .add_dependency(blocked.id, blocker.id)
+            .await
+            .unwrap();
+
+        let loaded_blocked = manager.load_by_id(blocked.id).await.unwrap().unwrap();
+        assert!(loaded_blocked.blocked);
+
+        let actionable = manager.load_actionable_tasks().await.unwrap();
+        assert_eq!(1, actionable.len());
+        assert_eq!(blocker.id, actionable[0].id);
+
+        manager
+            .remove_dependency(blocked.id, blocker.id)
+            .await
+            .unwrap();
+
+        let actionable = manager.load_actionable_tasks().await.unwrap();
+        assert_eq!(2, actionable.len());
+    }
+
+    #[tokio::test]
+    async fn completing_a_task_reports_the_tasks_it_unblocked() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let first_blocker = manager
+            .create_task(task_data("First Blocker"))
+            .await
+            .unwrap();
+        let second_blocker = manager
+            .create_task(task_data("Second Blocker"))
+            .await
+            .unwrap();
+        let waiting_on_one = manager
+            .create_task(task_data("Waiting On One"))
+            .await
+            .unwrap();
+        let waiting_on_both = manager
+            .create_task(task_data("Waiting On Both"))
+            .await
+            .unwrap();
+
+        manager
+            .add_dependency(waiting_on_one.id, first_blocker.id)
+            .await
+            .unwrap();
+        manager
+            .add_dependency(waiting_on_both.id, first_blocker.id)
+            .await
+            .unwrap();
+        manager
+            .add_dependency(waiting_on_both.id, second_blocker.id)
+            .await
+            .unwrap();
+
+        let completion = manager.complete_task(first_blocker.id).await.unwrap();
+
+        assert_eq!(1, completion.unblocked_tasks.len());
+        assert_eq!(waiting_on_one.id, completion.unblocked_tasks[0].id);
+
+        let completion = manager.complete_task(second_blocker.id).await.unwrap();
+
+        assert_eq!(1, completion.unblocked_tasks.len());
+        assert_eq!(waiting_on_both.id, completion.unblocked_tasks[0].id);
+        assert!(!completion.unblocked_tasks[0].blocked);
+    }
+
+    #[tokio::test]
+    async fn deleting_a_task_moves_it_and_its_subtasks_to_the_trash() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let task = manager.create_task(task_data("Parent")).await.unwrap();
+        let task_id = task.id;
+        let subtask = manager
+            .create_subtask_for_task(task, task_data("Child"))
+            .await
+            .unwrap();
+
+        manager.delete_task(task_id).await.unwrap();
+
+        assert!(manager.load_tasks(true).await.unwrap().is_empty());
+        assert!(manager.load_inbox().await.unwrap().is_empty());
+
+        let trash = manager.load_trash().await.unwrap();
+        assert_eq!(1, trash.len());
+        assert_eq!(task_id, trash[0].id);
+
+        let restored = manager.restore_task(task_id).await.unwrap();
+        assert!(restored.deleted_at_utc.is_none());
+
+        let subtasks = manager.load_subtasks_for_task(task_id).await.unwrap();
+        assert_eq!(1, subtasks.len());
+        assert_eq!(subtask.id, subtasks[0].id);
+        assert!(manager.load_trash().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn restoring_a_subtask_of_a_trashed_task_detaches_it() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let task = manager.create_task(task_data("Parent")).await.unwrap();
+        let task_id = task.id;
+        let subtask = manager
+            .create_subtask_for_task(task, task_data("Child"))
+            .await
+            .unwrap();
+
+        manager.delete_task(subtask.id).await.unwrap();
+        manager.delete_task(task_id).await.unwrap();
+
+        assert_eq!(2, manager.load_trash().await.unwrap().len());
+
+        let restored = manager.restore_task(subtask.id).await.unwrap();
+
+        assert!(restored.parent_task_id.is_none());
+        assert_eq!(1, manager.load_trash().await.unwrap().len());
+    }
+
+    #[tokio::test]
+    async fn purging_the_trash_only_removes_tasks_past_the_retention() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let old_task = manager.create_task(task_data("Old")).await.unwrap();
+        let recent_task = manager.create_task(task_data("Recent")).await.unwrap();
+
+        let mut repository = provider.task_repository().await.unwrap();
+        repository
+            .soft_delete_with_subtasks(old_task.id, Utc::now() - Duration::days(45))
+            .await
+            .unwrap();
+        manager.delete_task(recent_task.id).await.unwrap();
+
+        let mut pomodoro_session_repository = provider.pomodoro_session_repository().await.unwrap();
+        pomodoro_session_repository
+            .save(&PomodoroSession::new(
+                Some(old_task.id),
+                Utc::now() - Duration::days(50),
+                Utc::now() - Duration::days(50) + Duration::minutes(25),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(1, manager.purge_trash(30).await.unwrap());
+
+        let trash = manager.load_trash().await.unwrap();
+        assert_eq!(1, trash.len());
+        assert_eq!(recent_task.id, trash[0].id);
+        assert!(repository.find_by_id(old_task.id).await.unwrap().is_none());
+
+        // Its sessions and history are purged along with it
+        assert!(pomodoro_session_repository
+            .find_by_task(old_task.id)
+            .await
+            .unwrap()
+            .is_empty());
+        let mut history_repository = provider.history_repository().await.unwrap();
+        assert!(history_repository
+            .find_by_entity(HistoryEntityType::Task, old_task.id)
+            .await
+            .unwrap()
+            .is_empty());
+
+        assert_eq!(1, manager.empty_trash().await.unwrap());
+        assert!(manager.load_trash().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn archived_tasks_are_hidden_from_lists_and_completion_statistics() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let done = manager.create_task(task_data("Done")).await.unwrap();
+        let promoted = manager.create_task(task_data("Promoted")).await.unwrap();
+        manager.complete_task(done.id).await.unwrap();
+        manager.complete_task(promoted.id).await.unwrap();
+        manager.archive_task(promoted.id).await.unwrap();
+
+        let tasks = manager.load_tasks(true).await.unwrap();
+        assert_eq!(1, tasks.len());
+        assert_eq!(done.id, tasks[0].id);
+        assert_eq!(1, manager.load_completed_tasks().await.unwrap().len());
+
+        let statistics = manager.load_statistics().await.unwrap();
+        let completed: i64 = statistics[0]
+            .0
+            .values()
+            .map(|statistic| statistic.completed_tasks)
+            .sum();
+        assert_eq!(1, completed);
+
+        let archived = manager.load_archived_tasks().await.unwrap();
+        assert_eq!(1, archived.len());
+        assert_eq!(TaskStatus::Archived, archived[0].status());
+
+        let unarchived = manager.unarchive_task(promoted.id).await.unwrap();
+        assert_eq!(TaskStatus::Completed, unarchived.status());
+        assert!(manager.load_archived_tasks().await.unwrap().is_empty());
+        assert!(manager.unarchive_task(promoted.id).await.is_err());
+    }
+
+    #[test]
+    fn ranks_always_fit_between_two_others() {
+        let first = rank_between(None, None).unwrap();
+        let second = rank_between(Some(&first), None).unwrap();
+        assert!(first < second);
+
+        let mut lower = first.clone();
+        for _ in 0..50 {
+            let middle = rank_between(Some(&lower), Some(&second)).unwrap();
+            assert!(lower < middle && middle < second);
+            lower = middle;
+        }
+
+        let start = rank_between(None, Some("000001i")).unwrap();
+        assert!(start.as_str() < "000001i");
+        assert!(rank_between(Some("b"), Some("a")).is_err());
+        assert!(rank_between(None, Some("0")).is_err());
+    }
+
+    #[tokio::test]
+    async fn moving_a_task_keeps_the_rest_of_the_list_in_place() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let first = manager.create_task(task_data("First")).await.unwrap();
+        let second = manager.create_task(task_data("Second")).await.unwrap();
+        let third = manager.create_task(task_data("Third")).await.unwrap();
+
+        let moved = manager
+            .move_task(third.id, Some(first.id), Some(second.id))
+            .await
+            .unwrap();
+
+        // Editing a task does not change its place in the list
+        manager
+            .update_task(
+                first.id,
+                UpdatedTaskData {
+                    title: "First, renamed".to_string(),
+                    description: None,
+                    project_id: None,
+                    due_date: None,
+                    priority: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let inbox: Vec<_> = manager
+            .load_inbox()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.id)
+            .collect();
+        assert_eq!(vec![first.id, third.id, second.id], inbox);
+
+        // A subtask lives in its parent's list
+        let subtask = manager
+            .create_subtask_for_task(first.clone(), task_data("Subtask"))
+            .await
+            .unwrap();
+        assert!(manager
+            .move_task(subtask.id, Some(moved.id), None)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn it_loads_a_whole_subtree_with_completion_rollups() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let root = manager.create_task(task_data("Move house")).await.unwrap();
+        let root_id = root.id;
+        let packing = manager
+            .create_subtask_for_task(root, task_data("Pack"))
+            .await
+            .unwrap();
+        let packing_id = packing.id;
+        let books = manager
+            .create_subtask_for_task(packing.clone(), task_data("Pack books"))
+            .await
+            .unwrap();
+        let kitchen = manager
+            .create_subtask_for_task(packing, task_data("Pack kitchen"))
+            .await
+            .unwrap();
+        manager.complete_task(books.id).await.unwrap();
+        let boxes = manager.create_task(task_data("Buy boxes")).await.unwrap();
+        manager.add_dependency(kitchen.id, boxes.id).await.unwrap();
+
+        let tree = manager.load_task_tree(root_id).await.unwrap();
+
+        assert_eq!(0, tree.depth);
+        assert_eq!(3, tree.descendant_count);
+        assert_eq!(1, tree.completed_descendant_count);
+        assert_eq!(1, tree.children.len());
+
+        let packing = &tree.children[0];
+        assert_eq!(packing_id, packing.task.id);
+        assert_eq!(1, packing.depth);
+        assert_eq!(2, packing.children.len());
+        assert_eq!(2, packing.children[0].depth);
+        assert_eq!(1, packing.completed_descendant_count);
+        assert_eq!(2, packing.task.subtask_total);
+        assert_eq!(1, packing.task.subtask_completed);
+        assert!(packing
+            .children
+            .iter()
+            .any(|child| child.task.id == kitchen.id && child.task.blocked));
+    }
+
+    #[tokio::test]
+    async fn moving_a_task_under_another_parent_brings_its_subtree_along() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut projects_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        let project = projects_manager
+            .create_project("Garden".to_string(), None, None, None)
+            .await
+            .unwrap();
+
+        let new_parent = manager
+            .create_task(CreateTaskData {
+                title: "Spring cleanup".to_string(),
+                description: None,
+                project_id: Some(project.id.to_string()),
+                due_at_utc: None,
+                priority: None,
+            })
+            .await
+            .unwrap();
+        let task = manager.create_task(task_data("Fix fence")).await.unwrap();
+        let task_id = task.id;
+        let subtask = manager
+            .create_subtask_for_task(task, task_data("Buy nails"))
+            .await
+            .unwrap();
+
+        // A task cannot end up under its own subtask
+        assert!(manager
+            .move_task_to_parent(task_id, Some(subtask.id))
+            .await
+            .is_err());
+        assert!(manager
+            .move_task_to_parent(task_id, Some(task_id))
+            .await
+            .is_err());
+
+        let moved = manager
+            .move_task_to_parent(task_id, Some(new_parent.id))
+            .await
+            .unwrap();
+        assert_eq!(Some(new_parent.id), moved.parent_task_id);
+        assert_eq!(Some(project.id), moved.project_id);
+
+        let subtask = manager.load_task(subtask.id).await.unwrap();
+        assert_eq!(Some(project.id), subtask.project_id);
+        assert_eq!(Some(task_id), subtask.parent_task_id);
+
+        let detached = manager.move_task_to_parent(task_id, None).await.unwrap();
+        assert!(detached.parent_task_id.is_none());
+        assert_eq!(Some(project.id), detached.project_id);
+    }
+
+    #[tokio::test]
+    async fn completing_the_last_subtask_can_complete_the_parent() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider).with_auto_complete_parents(true);
+
+        let parent = manager.create_task(task_data("Groceries")).await.unwrap();
+        let parent_id = parent.id;
+        let milk = manager
+            .create_subtask_for_task(parent.clone(), task_data("Milk"))
+            .await
+            .unwrap();
+        let bread = manager
+            .create_subtask_for_task(parent, task_data("Bread"))
+            .await
+            .unwrap();
+
+        manager.complete_task(milk.id).await.unwrap();
+        let parent = manager.load_task(parent_id).await.unwrap();
+        assert_eq!(2, parent.subtask_total);
+        assert_eq!(1, parent.subtask_completed);
+        assert!(parent.completed_at_utc.is_none());
+
+        manager.complete_task(bread.id).await.unwrap();
+        let parent = manager.load_task(parent_id).await.unwrap();
+        assert_eq!(2, parent.subtask_completed);
+        assert!(parent.completed_at_utc.is_some());
+
+        // The subtask and the parent it completed are undone together
+        UndoManager::new(&provider).undo().await.unwrap();
+        let parent = manager.load_task(parent_id).await.unwrap();
+        assert!(parent.completed_at_utc.is_none());
+        assert_eq!(1, parent.subtask_completed);
+        UndoManager::new(&provider).redo().await.unwrap();
+        let parent = manager.load_task(parent_id).await.unwrap();
+        assert!(parent.completed_at_utc.is_some());
+
+        // The task setting wins over the global one
+        let other_parent = manager.create_task(task_data("Chores")).await.unwrap();
+        let other_parent_id = other_parent.id;
+        manager
+            .set_auto_complete(other_parent_id, Some(false))
+            .await
+            .unwrap();
+        let dishes = manager
+            .create_subtask_for_task(other_parent, task_data("Dishes"))
+            .await
+            .unwrap();
+        manager.complete_task(dishes.id).await.unwrap();
+        let other_parent = manager.load_task(other_parent_id).await.unwrap();
+        assert!(other_parent.completed_at_utc.is_none());
+    }
+
+    #[tokio::test]
+    async fn a_recurring_subtask_keeps_its_parent_open() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider).with_auto_complete_parents(true);
+
+        let parent = manager.create_task(task_data("Household")).await.unwrap();
+        let parent_id = parent.id;
+        let laundry = manager
+            .create_subtask_for_task(parent, task_data("Laundry"))
+            .await
+            .unwrap();
+
+        let mut recurring_task_repository = provider.recurring_task_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        RecurringTaskManager::new(
+            &mut recurring_task_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        )
+        .setup_recurring_task(laundry.id, Frequency::Weekly, 1)
+        .await
+        .unwrap();
+
+        manager.complete_task(laundry.id).await.unwrap();
+
+        // The next occurrence is a new open subtask of the same parent
+        let parent = manager.load_task(parent_id).await.unwrap();
+        assert!(parent.completed_at_utc.is_none());
+        assert_eq!(2, parent.subtask_total);
+        assert_eq!(1, parent.subtask_completed);
+    }
+
+    #[tokio::test]
+    async fn duplicating_a_task_copies_its_subtree_and_recurrence() {
+        let provider = setup_test_db().await.unwrap();
+        let manager = TaskManager::new(&provider);
+
+        let task = manager
+            .create_task(task_data("Weekly review"))
+            .await
+            .unwrap();
+        let task_id = task.id;
+        let subtask = manager
+            .create_subtask_for_task(task, task_data("Inbox zero"))
+            .await
+            .unwrap();
+        manager
+            .create_subtask_for_task(subtask, task_data("Archive newsletters"))
+            .await
+            .unwrap();
+
+        let mut recurring_task_repository = provider.recurring_task_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        RecurringTaskManager::new(
+            &mut recurring_task_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        )
+        .setup_recurring_task(task_id, Frequency::Weekly, 1)
+        .await
+        .unwrap();
+
+        let copy = manager.duplicate_task(task_id).await.unwrap();
+        assert_ne!(task_id, copy.id);
+        assert_eq!("Weekly review", copy.title);
+
+        let tree = manager.load_task_tree(copy.id).await.unwrap();
+        assert_eq!(2, tree.descendant_count);
+        assert_eq!("Inbox zero", tree.children[0].task.title);
+        assert_eq!(
+            "Archive newsletters",
+            tree.children[0].children[0].task.title
+        );
+
+        let recurring_copy = recurring_task_repository
+            .find_by_task_id(copy.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!("weekly", recurring_copy.frequency);
+
+        // The whole copy goes away in a single undo step
+        UndoManager::new(&provider).undo().await.unwrap();
+        assert!(manager.load_by_id(copy.id).await.unwrap().is_none());
+        assert_eq!(3, manager.load_tasks(false).await.unwrap().len());
+    }
 }
@@ -0,0 +1,86 @@
+use std::error::Error;
+use uuid::Uuid;
+
+use super::repository::TemplateRepository;
+use super::ProjectTemplate;
+use crate::project::repository::ProjectRepository;
+use crate::task::repository::TaskRepository;
+use crate::task::{Task, TaskStatus};
+
+pub struct TemplateManager<'a> {
+    template_repository: &'a mut dyn TemplateRepository,
+    project_repository: &'a mut dyn ProjectRepository,
+    task_repository: &'a mut dyn TaskRepository,
+}
+
+impl<'a> TemplateManager<'a> {
+    pub fn new(
+        template_repository: &'a mut dyn TemplateRepository,
+        project_repository: &'a mut dyn ProjectRepository,
+        task_repository: &'a mut dyn TaskRepository,
+    ) -> Self {
+        Self {
+            template_repository,
+            project_repository,
+            task_repository,
+        }
+    }
+
+    pub async fn load_all(&mut self) -> Result<Vec<ProjectTemplate>, Box<dyn Error>> {
+        self.template_repository
+            .find_all()
+            .await
+            .map_err(|e| e.into())
+    }
+
+    pub async fn load_by_id(
+        &mut self,
+        template_id: Uuid,
+    ) -> Result<ProjectTemplate, Box<dyn Error>> {
+        self.template_repository
+            .find_by_id(template_id)
+            .await?
+            .ok_or("Template not found".into())
+    }
+
+    /// Saves a project as a template, completed tasks included since they
+    /// are usually the steps worth repeating.
+    pub async fn save_project_as_template(
+        &mut self,
+        project_id: Uuid,
+        title: Option<String>,
+    ) -> Result<ProjectTemplate, Box<dyn Error>> {
+        let project = self
+            .project_repository
+            .find_by_id(project_id)
+            .await?
+            .ok_or("Project not found")?;
+        let sections = self.project_repository.find_sections(project_id).await?;
+        let tasks: Vec<Task> = self
+            .task_repository
+            .find_all_by_project(project_id)
+            .await?
+            .into_iter()
+            .filter(|task| task.status() != TaskStatus::Archived && task.deleted_at_utc.is_none())
+            .collect();
+
+        let mut template = ProjectTemplate::from_project(&project, &sections, &tasks);
+        if let Some(title) = title {
+            let title = title.trim().to_string();
+            if title.is_empty() {
+                return Err("Template title cannot be empty".into());
+            }
+            template.title = title;
+        }
+        self.template_repository.save(&mut template).await?;
+
+        Ok(template)
+    }
+
+    pub async fn delete_template(&mut self, template_id: Uuid) -> Result<(), Box<dyn Error>> {
+        let template = self.load_by_id(template_id).await?;
+        self.template_repository.delete(&template).await?;
+
+        Ok(())
+    }
+}
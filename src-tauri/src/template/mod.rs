@@ -0,0 +1,102 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::project::{Project, Section};
+use crate::task::{Priority, Task};
+
+pub mod manager;
+pub mod repository;
+pub mod tauri;
+mod test;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectTemplate {
+    pub id: Uuid,
+    pub title: String,
+    pub emoji: Option<String>,
+    pub color: Option<String>,
+    pub description: Option<String>,
+    pub sections: Vec<TemplateSection>,
+    pub tasks: Vec<TemplateTask>,
+    pub created_at_utc: DateTime<Utc>,
+    pub updated_at_utc: DateTime<Utc>,
+}
+
+// Keys only link the entries of a template together, new ids are generated
+// every time the template is instantiated
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TemplateSection {
+    pub key: Uuid,
+    pub title: String,
+    pub position: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TemplateTask {
+    pub key: Uuid,
+    pub parent_key: Option<Uuid>,
+    pub section_key: Option<Uuid>,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub position: String,
+    pub auto_complete: Option<bool>,
+    #[serde(default)]
+    pub estimated_minutes: Option<i64>,
+    // Seconds after the start date chosen when the template is instantiated
+    pub due_offset_seconds: Option<i64>,
+}
+
+impl ProjectTemplate {
+    /// Captures a project's sections and tasks, due dates being stored
+    /// relative to the earliest one so the first due task lands on the start
+    /// date of each new project.
+    pub fn from_project(project: &Project, sections: &[Section], tasks: &[Task]) -> Self {
+        let start_date = tasks.iter().filter_map(|task| task.due_at_utc).min();
+        let now = Utc::now();
+
+        Self {
+            id: Uuid::now_v7(),
+            title: project.title.clone(),
+            emoji: project.emoji.clone(),
+            color: project.color.clone(),
+            description: project.description.clone(),
+            sections: sections
+                .iter()
+                .map(|section| TemplateSection {
+                    key: section.id,
+                    title: section.title.clone(),
+                    position: section.position,
+                })
+                .collect(),
+            tasks: tasks
+                .iter()
+                .map(|task| TemplateTask {
+                    key: task.id,
+                    parent_key: task.parent_task_id,
+                    section_key: task.section_id,
+                    title: task.title.clone(),
+                    description: task.description.clone(),
+                    priority: task.priority,
+                    position: task.position.clone(),
+                    auto_complete: task.auto_complete,
+                    estimated_minutes: task.estimated_minutes,
+                    due_offset_seconds: task
+                        .due_at_utc
+                        .zip(start_date)
+                        .map(|(due_at_utc, start_date)| (due_at_utc - start_date).num_seconds()),
+                })
+                .collect(),
+            created_at_utc: now,
+            updated_at_utc: now,
+        }
+    }
+}
+
+impl TemplateTask {
+    pub fn due_at_utc(&self, start_date: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.due_offset_seconds
+            .map(|offset| start_date + Duration::seconds(offset))
+    }
+}
@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{pool::PoolConnection, Row, Sqlite};
+use uuid::Uuid;
+
+use super::ProjectTemplate;
+
+#[async_trait]
+pub trait TemplateRepository: Send + Sync {
+    async fn save(&mut self, template: &mut ProjectTemplate) -> Result<(), sqlx::Error>;
+    async fn delete(&mut self, template: &ProjectTemplate) -> Result<(), sqlx::Error>;
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<ProjectTemplate>, sqlx::Error>;
+    async fn find_all(&mut self) -> Result<Vec<ProjectTemplate>, sqlx::Error>;
+}
+
+pub struct SqliteTemplateRepository {
+    connection: PoolConnection<Sqlite>,
+}
+
+impl SqliteTemplateRepository {
+    pub fn new(connection: PoolConnection<Sqlite>) -> Self {
+        Self { connection }
+    }
+
+    fn row_to_template(row: sqlx::sqlite::SqliteRow) -> Result<ProjectTemplate, sqlx::Error> {
+        let id = Uuid::parse_str(row.get("id")).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let sections = serde_json::from_str(row.get("sections"))
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let tasks =
+            serde_json::from_str(row.get("tasks")).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let created_at_utc = DateTime::parse_from_rfc3339(row.get("created_at_utc"))
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?
+            .with_timezone(&Utc);
+        let updated_at_utc = DateTime::parse_from_rfc3339(row.get("updated_at_utc"))
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?
+            .with_timezone(&Utc);
+
+        Ok(ProjectTemplate {
+            id,
+            title: row.get("title"),
+            emoji: row.get("emoji"),
+            color: row.get("color"),
+            description: row.get("description"),
+            sections,
+            tasks,
+            created_at_utc,
+            updated_at_utc,
+        })
+    }
+}
+
+#[async_trait]
+impl TemplateRepository for SqliteTemplateRepository {
+    async fn save(&mut self, template: &mut ProjectTemplate) -> Result<(), sqlx::Error> {
+        let sections =
+            serde_json::to_string(&template.sections).map_err(|e| sqlx::Error::Encode(e.into()))?;
+        let tasks =
+            serde_json::to_string(&template.tasks).map_err(|e| sqlx::Error::Encode(e.into()))?;
+
+        template.updated_at_utc = Utc::now();
+
+        sqlx::query(
+            "INSERT INTO project_templates (id, title, emoji, color, description, sections, tasks, created_at_utc, updated_at_utc)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            ON CONFLICT(id) DO UPDATE SET title = excluded.title, emoji = excluded.emoji, color = excluded.color,
+                description = excluded.description, sections = excluded.sections, tasks = excluded.tasks,
+                updated_at_utc = excluded.updated_at_utc",
+        )
+        .bind(template.id.to_string())
+        .bind(&template.title)
+        .bind(&template.emoji)
+        .bind(&template.color)
+        .bind(&template.description)
+        .bind(sections)
+        .bind(tasks)
+        .bind(template.created_at_utc.to_rfc3339())
+        .bind(template.updated_at_utc.to_rfc3339())
+        .execute(&mut *self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&mut self, template: &ProjectTemplate) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM project_templates WHERE id = ?1")
+            .bind(template.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<ProjectTemplate>, sqlx::Error> {
+        sqlx::query("SELECT * FROM project_templates WHERE id = ?1 LIMIT 1")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.connection)
+            .await?
+            .map(Self::row_to_template)
+            .transpose()
+    }
+
+    async fn find_all(&mut self) -> Result<Vec<ProjectTemplate>, sqlx::Error> {
+        sqlx::query("SELECT * FROM project_templates ORDER BY title ASC")
+            .fetch_all(&mut *self.connection)
+            .await?
+            .into_iter()
+            .map(Self::row_to_template)
+            .collect()
+    }
+}
@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::project::manager::ProjectsManager;
+use crate::repository::RepositoryProvider;
+use crate::template::manager::TemplateManager;
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn save_project_as_template_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    project_id: String,
+    title: Option<String>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running save project as template command for: {}",
+        project_id
+    );
+
+    let project_uuid = Uuid::parse_str(&project_id).map_err(|e| handle_error(&e))?;
+
+    let mut template_repository = repository_provider
+        .template_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut template_manager = TemplateManager::new(
+        &mut template_repository,
+        &mut project_repository,
+        &mut task_repository,
+    );
+
+    let template = template_manager
+        .save_project_as_template(project_uuid, title)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&template).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn delete_template_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    template_id: String,
+) -> Result<String, String> {
+    log::debug!("Running delete template command for: {}", template_id);
+
+    let template_uuid = Uuid::parse_str(&template_id).map_err(|e| handle_error(&e))?;
+
+    let mut template_repository = repository_provider
+        .template_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut template_manager = TemplateManager::new(
+        &mut template_repository,
+        &mut project_repository,
+        &mut task_repository,
+    );
+
+    template_manager
+        .delete_template(template_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(format!(
+        "Template with ID {} deleted successfully",
+        &template_id
+    ))
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn create_project_from_template_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    template_id: String,
+    start_date: String,
+) -> Result<String, String> {
+    log::debug!(
+        "Running create project from template command for: {} | {}",
+        template_id,
+        start_date
+    );
+
+    let template_uuid = Uuid::parse_str(&template_id).map_err(|e| handle_error(&e))?;
+    let start_date = DateTime::parse_from_rfc3339(&start_date)
+        .map_err(|e| handle_error(&e))?
+        .with_timezone(&Utc);
+
+    let mut template_repository = repository_provider
+        .template_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut undo_repository = repository_provider
+        .undo_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+
+    let template = TemplateManager::new(
+        &mut template_repository,
+        &mut project_repository,
+        &mut task_repository,
+    )
+    .load_by_id(template_uuid)
+    .await
+    .map_err(|e| handle_error(&*e))?;
+
+    let mut projects_manager = ProjectsManager::new(
+        &mut project_repository,
+        &mut task_repository,
+        &mut undo_repository,
+    );
+    let project = projects_manager
+        .create_project_from_template(&template, start_date)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&project).unwrap())
+}
@@ -0,0 +1,37 @@
+use tauri::State;
+
+use crate::errors::handle_error;
+use crate::repository::RepositoryProvider;
+use crate::template::manager::TemplateManager;
+
+#[tauri::command]
+pub async fn load_templates_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load templates command");
+
+    let mut template_repository = repository_provider
+        .template_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut project_repository = repository_provider
+        .project_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut template_manager = TemplateManager::new(
+        &mut template_repository,
+        &mut project_repository,
+        &mut task_repository,
+    );
+
+    let templates = template_manager
+        .load_all()
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&templates).unwrap())
+}
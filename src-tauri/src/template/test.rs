@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod manager_test {
+    use crate::project::manager::ProjectsManager;
+    use crate::repository::RepositoryProvider;
+    use crate::task::manager::TaskManager;
+    use crate::task::CreateTaskData;
+    use crate::template::manager::TemplateManager;
+
+    use chrono::{Duration, TimeZone, Utc};
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+    use uuid::Uuid;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    fn task_data(title: &str, project_id: Uuid) -> CreateTaskData {
+        CreateTaskData {
+            title: title.to_string(),
+            description: None,
+            project_id: Some(project_id.to_string()),
+            due_at_utc: None,
+            priority: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_creates_a_project_from_a_saved_template() {
+        let provider = setup_test_db().await.unwrap();
+        let mut template_repository = provider.template_repository().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+
+        let mut projects_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        let project = projects_manager
+            .create_project("Onboarding".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let section = projects_manager
+            .create_section(project.id, "First week".to_string())
+            .await
+            .unwrap();
+
+        let task_manager = TaskManager::new(&provider);
+        let first_due_date = Utc::now();
+        let laptop = task_manager
+            .create_task(CreateTaskData {
+                due_at_utc: Some(first_due_date.to_rfc3339()),
+                ..task_data("Order laptop", project.id)
+            })
+            .await
+            .unwrap();
+        projects_manager
+            .move_task_to_section(laptop.id, Some(section.id))
+            .await
+            .unwrap();
+        let accounts = task_manager
+            .create_subtask_for_task(
+                laptop.clone(),
+                CreateTaskData {
+                    due_at_utc: Some((first_due_date + Duration::days(2)).to_rfc3339()),
+                    ..task_data("Create accounts", project.id)
+                },
+            )
+            .await
+            .unwrap();
+        task_manager.complete_task(accounts.id).await.unwrap();
+
+        let mut template_manager = TemplateManager::new(
+            &mut template_repository,
+            &mut project_repository,
+            &mut task_repository,
+        );
+        let template = template_manager
+            .save_project_as_template(project.id, Some("New hire".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(1, template.sections.len());
+        assert_eq!(2, template.tasks.len());
+        assert_eq!(1, template_manager.load_all().await.unwrap().len());
+
+        let template = template_manager.load_by_id(template.id).await.unwrap();
+        let start_date = Utc.with_ymd_and_hms(2025, 6, 2, 9, 0, 0).unwrap();
+        let mut projects_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        let new_project = projects_manager
+            .create_project_from_template(&template, start_date)
+            .await
+            .unwrap();
+        assert_eq!("New hire", new_project.title);
+
+        let detail = projects_manager
+            .load_project_detail(new_project.id, true)
+            .await
+            .unwrap();
+        assert_eq!("First week", detail.sections[0].section.title);
+        let new_laptop = &detail.sections[0].tasks[0];
+        assert_eq!("Order laptop", new_laptop.title);
+        assert_eq!(Some(start_date), new_laptop.due_at_utc);
+
+        let new_accounts = task_manager
+            .load_subtasks_for_task(new_laptop.id)
+            .await
+            .unwrap();
+        assert_eq!(1, new_accounts.len());
+        assert!(new_accounts[0].completed_at_utc.is_none());
+        assert_eq!(
+            Some(start_date + Duration::days(2)),
+            new_accounts[0].due_at_utc
+        );
+
+        let mut template_manager = TemplateManager::new(
+            &mut template_repository,
+            &mut project_repository,
+            &mut task_repository,
+        );
+        template_manager.delete_template(template.id).await.unwrap();
+        assert!(template_manager.load_all().await.unwrap().is_empty());
+    }
+}
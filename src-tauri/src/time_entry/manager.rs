@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use std::error::Error;
+use uuid::Uuid;
+
+use super::repository::TimeEntryRepository;
+use super::TimeEntry;
+use crate::task::repository::TaskRepository;
+
+pub struct TimeEntryManager<'a> {
+    time_entry_repository: &'a mut dyn TimeEntryRepository,
+    task_repository: &'a mut dyn TaskRepository,
+}
+
+impl<'a> TimeEntryManager<'a> {
+    pub fn new(
+        time_entry_repository: &'a mut dyn TimeEntryRepository,
+        task_repository: &'a mut dyn TaskRepository,
+    ) -> Self {
+        Self {
+            time_entry_repository,
+            task_repository,
+        }
+    }
+
+    pub async fn load_running_timer(&mut self) -> Result<Option<TimeEntry>, Box<dyn Error>> {
+        self.time_entry_repository
+            .find_running()
+            .await
+            .map_err(|e| e.into())
+    }
+
+    pub async fn load_time_entries(
+        &mut self,
+        task_id: Uuid,
+    ) -> Result<Vec<TimeEntry>, Box<dyn Error>> {
+        self.time_entry_repository
+            .find_by_task(task_id)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    /// Starts tracking time on a task. Only one timer runs at a time, so the
+    /// timer of another task is stopped first.
+    pub async fn start_timer(&mut self, task_id: Uuid) -> Result<TimeEntry, Box<dyn Error>> {
+        self.check_task_is_trackable(task_id).await?;
+
+        if let Some(running_entry) = self.time_entry_repository.find_running().await? {
+            if running_entry.task_id == task_id {
+                return Ok(running_entry);
+            }
+            self.stop_timer().await?;
+        }
+
+        let mut time_entry = TimeEntry::new(task_id, Utc::now(), None);
+        self.time_entry_repository.save(&mut time_entry).await?;
+
+        Ok(time_entry)
+    }
+
+    pub async fn stop_timer(&mut self) -> Result<TimeEntry, Box<dyn Error>> {
+        let mut time_entry = self
+            .time_entry_repository
+            .find_running()
+            .await?
+            .ok_or("No timer is running")?;
+
+        time_entry.ended_at_utc = Some(Utc::now());
+        self.time_entry_repository.save(&mut time_entry).await?;
+
+        Ok(time_entry)
+    }
+
+    pub async fn add_time_entry(
+        &mut self,
+        task_id: Uuid,
+        started_at_utc: DateTime<Utc>,
+        ended_at_utc: DateTime<Utc>,
+    ) -> Result<TimeEntry, Box<dyn Error>> {
+        if ended_at_utc <= started_at_utc {
+            return Err("A time entry must end after it starts".into());
+        }
+        self.check_task_is_trackable(task_id).await?;
+
+        let mut time_entry = TimeEntry::new(task_id, started_at_utc, Some(ended_at_utc));
+        self.time_entry_repository.save(&mut time_entry).await?;
+
+        Ok(time_entry)
+    }
+
+    pub async fn delete_time_entry(&mut self, time_entry_id: Uuid) -> Result<(), Box<dyn Error>> {
+        let time_entry = self
+            .time_entry_repository
+            .find_by_id(time_entry_id)
+            .await?
+            .ok_or("Time entry not found")?;
+
+        self.time_entry_repository.delete(&time_entry).await?;
+
+        Ok(())
+    }
+
+    async fn check_task_is_trackable(&mut self, task_id: Uuid) -> Result<(), Box<dyn Error>> {
+        let task = self
+            .task_repository
+            .find_by_id(task_id)
+            .await?
+            .ok_or("Task not found")?;
+
+        if task.deleted_at_utc.is_some() {
+            return Err("Cannot track time on a deleted task".into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+use uuid::{fmt::Hyphenated, Uuid};
+
+pub mod manager;
+pub mod repository;
+pub mod tauri;
+mod test;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct TimeEntry {
+    #[sqlx(try_from = "Hyphenated")]
+    pub id: Uuid,
+    #[sqlx(try_from = "Hyphenated")]
+    pub task_id: Uuid,
+    pub started_at_utc: DateTime<Utc>,
+    // None while the timer is running
+    pub ended_at_utc: Option<DateTime<Utc>>,
+    pub created_at_utc: DateTime<Utc>,
+    pub updated_at_utc: DateTime<Utc>,
+}
+
+impl TimeEntry {
+    pub fn new(
+        task_id: Uuid,
+        started_at_utc: DateTime<Utc>,
+        ended_at_utc: Option<DateTime<Utc>>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::now_v7(),
+            task_id,
+            started_at_utc,
+            ended_at_utc,
+            created_at_utc: now,
+            updated_at_utc: now,
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.ended_at_utc.is_none()
+    }
+}
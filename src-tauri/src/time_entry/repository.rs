@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{pool::PoolConnection, Sqlite};
+use uuid::Uuid;
+
+use super::TimeEntry;
+
+#[async_trait]
+pub trait TimeEntryRepository: Send + Sync {
+    async fn save(&mut self, time_entry: &mut TimeEntry) -> Result<(), sqlx::Error>;
+    async fn delete(&mut self, time_entry: &TimeEntry) -> Result<(), sqlx::Error>;
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<TimeEntry>, sqlx::Error>;
+    async fn find_running(&mut self) -> Result<Option<TimeEntry>, sqlx::Error>;
+    async fn find_by_task(&mut self, task_id: Uuid) -> Result<Vec<TimeEntry>, sqlx::Error>;
+}
+
+pub struct SqliteTimeEntryRepository {
+    connection: PoolConnection<Sqlite>,
+}
+
+impl SqliteTimeEntryRepository {
+    pub fn new(connection: PoolConnection<Sqlite>) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepository for SqliteTimeEntryRepository {
+    async fn save(&mut self, time_entry: &mut TimeEntry) -> Result<(), sqlx::Error> {
+        time_entry.updated_at_utc = Utc::now();
+
+        sqlx::query(
+            "INSERT INTO time_entries (id, task_id, started_at_utc, ended_at_utc, created_at_utc, updated_at_utc)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            ON CONFLICT(id) DO UPDATE SET task_id = excluded.task_id, started_at_utc = excluded.started_at_utc,
+                ended_at_utc = excluded.ended_at_utc, updated_at_utc = excluded.updated_at_utc",
+        )
+        .bind(time_entry.id.to_string())
+        .bind(time_entry.task_id.to_string())
+        .bind(time_entry.started_at_utc.to_rfc3339())
+        .bind(time_entry.ended_at_utc.map(|date| date.to_rfc3339()))
+        .bind(time_entry.created_at_utc.to_rfc3339())
+        .bind(time_entry.updated_at_utc.to_rfc3339())
+        .execute(&mut *self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn delete(&mut self, time_entry: &TimeEntry) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM time_entries WHERE id = ?1")
+            .bind(time_entry.id.to_string())
+            .execute(&mut *self.connection)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_id(&mut self, id: Uuid) -> Result<Option<TimeEntry>, sqlx::Error> {
+        sqlx::query_as::<_, TimeEntry>("SELECT * FROM time_entries WHERE id = ?1 LIMIT 1")
+            .bind(id.to_string())
+            .fetch_optional(&mut *self.connection)
+            .await
+    }
+
+    async fn find_running(&mut self) -> Result<Option<TimeEntry>, sqlx::Error> {
+        sqlx::query_as::<_, TimeEntry>(
+            "SELECT * FROM time_entries WHERE ended_at_utc IS NULL LIMIT 1",
+        )
+        .fetch_optional(&mut *self.connection)
+        .await
+    }
+
+    async fn find_by_task(&mut self, task_id: Uuid) -> Result<Vec<TimeEntry>, sqlx::Error> {
+        sqlx::query_as::<_, TimeEntry>(
+            "SELECT * FROM time_entries WHERE task_id = ?1 ORDER BY started_at_utc DESC",
+        )
+        .bind(task_id.to_string())
+        .fetch_all(&mut *self.connection)
+        .await
+    }
+}
@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::repository::RepositoryProvider;
+use crate::time_entry::manager::TimeEntryManager;
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn start_timer_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    task_id: String,
+) -> Result<String, String> {
+    log::debug!("Running start timer command for: {}", task_id);
+
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let mut time_entry_repository = repository_provider
+        .time_entry_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut time_entry_manager =
+        TimeEntryManager::new(&mut time_entry_repository, &mut task_repository);
+
+    let time_entry = time_entry_manager
+        .start_timer(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&time_entry).unwrap())
+}
+
+#[tauri::command]
+pub async fn stop_timer_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running stop timer command");
+
+    let mut time_entry_repository = repository_provider
+        .time_entry_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut time_entry_manager =
+        TimeEntryManager::new(&mut time_entry_repository, &mut task_repository);
+
+    let time_entry = time_entry_manager
+        .stop_timer()
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&time_entry).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn add_time_entry_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    task_id: String,
+    started_at_utc: DateTime<Utc>,
+    ended_at_utc: DateTime<Utc>,
+) -> Result<String, String> {
+    log::debug!(
+        "Running add time entry command for: {} | {} - {}",
+        task_id,
+        started_at_utc,
+        ended_at_utc
+    );
+
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let mut time_entry_repository = repository_provider
+        .time_entry_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut time_entry_manager =
+        TimeEntryManager::new(&mut time_entry_repository, &mut task_repository);
+
+    let time_entry = time_entry_manager
+        .add_time_entry(task_uuid, started_at_utc, ended_at_utc)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&time_entry).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn delete_time_entry_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    time_entry_id: String,
+) -> Result<String, String> {
+    log::debug!("Running delete time entry command for: {}", time_entry_id);
+
+    let time_entry_uuid = Uuid::parse_str(&time_entry_id).map_err(|e| handle_error(&e))?;
+
+    let mut time_entry_repository = repository_provider
+        .time_entry_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut time_entry_manager =
+        TimeEntryManager::new(&mut time_entry_repository, &mut task_repository);
+
+    time_entry_manager
+        .delete_time_entry(time_entry_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(format!(
+        "Time entry with ID {} deleted successfully",
+        &time_entry_id
+    ))
+}
@@ -0,0 +1,59 @@
+use tauri::State;
+use uuid::Uuid;
+
+use crate::errors::handle_error;
+use crate::repository::RepositoryProvider;
+use crate::time_entry::manager::TimeEntryManager;
+
+#[tauri::command]
+pub async fn load_running_timer_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load running timer command");
+
+    let mut time_entry_repository = repository_provider
+        .time_entry_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut time_entry_manager =
+        TimeEntryManager::new(&mut time_entry_repository, &mut task_repository);
+
+    let time_entry = time_entry_manager
+        .load_running_timer()
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&time_entry).unwrap())
+}
+
+#[tauri::command(rename_all = "camelCase")]
+pub async fn load_time_entries_command(
+    repository_provider: State<'_, RepositoryProvider>,
+    task_id: String,
+) -> Result<String, String> {
+    log::debug!("Running load time entries command for: {}", task_id);
+
+    let task_uuid = Uuid::parse_str(&task_id).map_err(|e| handle_error(&e))?;
+
+    let mut time_entry_repository = repository_provider
+        .time_entry_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut task_repository = repository_provider
+        .task_repository()
+        .await
+        .map_err(|e| handle_error(&e))?;
+    let mut time_entry_manager =
+        TimeEntryManager::new(&mut time_entry_repository, &mut task_repository);
+
+    let time_entries = time_entry_manager
+        .load_time_entries(task_uuid)
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&time_entries).unwrap())
+}
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod manager_test {
+    use crate::chart::manager::ChartManager;
+    use crate::project::manager::ProjectsManager;
+    use crate::repository::RepositoryProvider;
+    use crate::task::manager::TaskManager;
+    use crate::task::CreateTaskData;
+    use crate::time_entry::manager::TimeEntryManager;
+
+    use chrono::{Duration, Utc};
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    fn task_data(title: &str, project_id: Option<String>) -> CreateTaskData {
+        CreateTaskData {
+            title: title.to_string(),
+            description: None,
+            project_id,
+            due_at_utc: None,
+            priority: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn starting_a_timer_stops_the_running_one() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let first_task = task_manager
+            .create_task(task_data("Write report", None))
+            .await
+            .unwrap();
+        let second_task = task_manager
+            .create_task(task_data("Review report", None))
+            .await
+            .unwrap();
+
+        let mut time_entry_repository = provider.time_entry_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut manager = TimeEntryManager::new(&mut time_entry_repository, &mut task_repository);
+
+        let first_entry = manager.start_timer(first_task.id).await.unwrap();
+        assert_eq!(
+            first_entry.id,
+            manager.start_timer(first_task.id).await.unwrap().id
+        );
+
+        let second_entry = manager.start_timer(second_task.id).await.unwrap();
+        let running_entry = manager.load_running_timer().await.unwrap().unwrap();
+        assert_eq!(second_entry.id, running_entry.id);
+        assert!(!manager.load_time_entries(first_task.id).await.unwrap()[0].is_running());
+
+        manager.stop_timer().await.unwrap();
+        assert!(manager.load_running_timer().await.unwrap().is_none());
+        assert!(manager.stop_timer().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn it_totals_tracked_time_per_task_and_project() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let project = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        )
+        .create_project("Website".to_string(), None, None, None)
+        .await
+        .unwrap();
+
+        let task_manager = TaskManager::new(&provider);
+        let task = task_manager
+            .create_task(task_data("Design header", Some(project.id.to_string())))
+            .await
+            .unwrap();
+        task_manager.set_estimate(task.id, Some(90)).await.unwrap();
+        assert!(task_manager.set_estimate(task.id, Some(0)).await.is_err());
+
+        let mut time_entry_repository = provider.time_entry_repository().await.unwrap();
+        let mut manager = TimeEntryManager::new(&mut time_entry_repository, &mut task_repository);
+        let started_at = Utc::now() - Duration::hours(3);
+        manager
+            .add_time_entry(task.id, started_at, started_at + Duration::minutes(45))
+            .await
+            .unwrap();
+        manager
+            .add_time_entry(
+                task.id,
+                started_at + Duration::hours(1),
+                started_at + Duration::hours(2),
+            )
+            .await
+            .unwrap();
+        assert!(manager
+            .add_time_entry(task.id, started_at, started_at)
+            .await
+            .is_err());
+
+        let chart_manager = ChartManager::new(&provider.pool);
+        let since = Utc::now() - Duration::days(1);
+        let until = Utc::now();
+
+        let task_stats = chart_manager
+            .load_task_time_stats(since, until)
+            .await
+            .unwrap();
+        assert_eq!(1, task_stats.len());
+        assert_eq!(Some(90), task_stats[0].estimated_minutes);
+        assert_eq!(105 * 60, task_stats[0].tracked_seconds);
+
+        let project_stats = chart_manager
+            .load_project_time_stats(since, until)
+            .await
+            .unwrap();
+        assert_eq!(1, project_stats.len());
+        assert_eq!("Website", project_stats[0].project_title);
+        assert_eq!(105 * 60, project_stats[0].tracked_seconds);
+    }
+}
@@ -0,0 +1,60 @@
+use std::error::Error;
+
+use super::repository::UndoRepository;
+use super::{UndoDirection, UndoOperation, UndoState};
+use crate::repository::RepositoryProvider;
+
+pub struct UndoManager<'a> {
+    repository_provider: &'a RepositoryProvider,
+}
+
+impl<'a> UndoManager<'a> {
+    pub fn new(repository_provider: &'a RepositoryProvider) -> Self {
+        Self {
+            repository_provider,
+        }
+    }
+
+    pub async fn undo(&self) -> Result<Option<UndoOperation>, Box<dyn Error>> {
+        let mut repository = self.repository_provider.undo_repository().await?;
+
+        let mut operation = match repository.find_last_applied().await? {
+            Some(operation) => operation,
+            None => return Ok(None),
+        };
+
+        repository
+            .apply(&mut operation, UndoDirection::Undo)
+            .await?;
+        Ok(Some(operation))
+    }
+
+    pub async fn redo(&self) -> Result<Option<UndoOperation>, Box<dyn Error>> {
+        let mut repository = self.repository_provider.undo_repository().await?;
+
+        let mut operation = match repository.find_last_undone().await? {
+            Some(operation) => operation,
+            None => return Ok(None),
+        };
+
+        repository
+            .apply(&mut operation, UndoDirection::Redo)
+            .await?;
+        Ok(Some(operation))
+    }
+
+    pub async fn load_state(&self) -> Result<UndoState, Box<dyn Error>> {
+        let mut repository = self.repository_provider.undo_repository().await?;
+
+        Ok(UndoState {
+            undo: repository
+                .find_last_applied()
+                .await?
+                .map(|operation| operation.description),
+            redo: repository
+                .find_last_undone()
+                .await?
+                .map(|operation| operation.description),
+        })
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::project::{Project, Section};
+use crate::recurring_task::RecurringTask;
+use crate::task::Task;
+
+pub mod manager;
+pub mod repository;
+pub mod tauri;
+mod test;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "entity", rename_all = "snake_case")]
+pub enum UndoChange {
+    Task {
+        before: Option<Task>,
+        after: Option<Task>,
+    },
+    Project {
+        before: Option<Project>,
+        after: Option<Project>,
+    },
+    RecurringTask {
+        before: Option<RecurringTask>,
+        after: Option<RecurringTask>,
+    },
+    Section {
+        before: Option<Section>,
+        after: Option<Section>,
+    },
+    // Whether the task depended on the other one before and after the change
+    Dependency {
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+        before: bool,
+        after: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UndoDirection {
+    Undo,
+    Redo,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UndoOperation {
+    pub id: Uuid,
+    pub description: String,
+    pub changes: Vec<UndoChange>,
+    pub created_at_utc: DateTime<Utc>,
+    pub undone_at_utc: Option<DateTime<Utc>>,
+}
+
+impl UndoOperation {
+    pub fn new(description: &str) -> Self {
+        Self {
+            id: Uuid::now_v7(),
+            description: description.to_string(),
+            changes: Vec::new(),
+            created_at_utc: Utc::now(),
+            undone_at_utc: None,
+        }
+    }
+
+    pub fn record_task(&mut self, before: Option<Task>, after: Option<Task>) {
+        if before.is_some() || after.is_some() {
+            self.changes.push(UndoChange::Task { before, after });
+        }
+    }
+
+    // Pairs snapshots of the same tasks taken before and after a change
+    pub fn record_tasks(&mut self, before: Vec<Task>, after: Vec<Task>) {
+        let mut before = before;
+        for task in after {
+            let previous = before
+                .iter()
+                .position(|previous| previous.id == task.id)
+                .map(|index| before.remove(index));
+            self.record_task(previous, Some(task));
+        }
+        for task in before {
+            self.record_task(Some(task), None);
+        }
+    }
+
+    pub fn record_project(&mut self, before: Option<Project>, after: Option<Project>) {
+        if before.is_some() || after.is_some() {
+            self.changes.push(UndoChange::Project { before, after });
+        }
+    }
+
+    pub fn record_recurring_task(
+        &mut self,
+        before: Option<RecurringTask>,
+        after: Option<RecurringTask>,
+    ) {
+        if before.is_some() || after.is_some() {
+            self.changes
+                .push(UndoChange::RecurringTask { before, after });
+        }
+    }
+
+    pub fn record_section(&mut self, before: Option<Section>, after: Option<Section>) {
+        if before.is_some() || after.is_some() {
+            self.changes.push(UndoChange::Section { before, after });
+        }
+    }
+
+    pub fn record_dependency(
+        &mut self,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+        before: bool,
+        after: bool,
+    ) {
+        if before != after {
+            self.changes.push(UndoChange::Dependency {
+                task_id,
+                depends_on_task_id,
+                before,
+                after,
+            });
+        }
+    }
+
+    // The task snapshots recorded so far, as before and after pairs in recording order
+    pub fn task_changes(&self) -> impl Iterator<Item = (Option<&Task>, Option<&Task>)> {
+        self.changes.iter().filter_map(|change| match change {
+            UndoChange::Task { before, after } => Some((before.as_ref(), after.as_ref())),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UndoState {
+    pub undo: Option<String>,
+    pub redo: Option<String>,
+}
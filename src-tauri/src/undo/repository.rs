@@ -0,0 +1,473 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{pool::PoolConnection, Connection, Row, Sqlite, SqliteConnection};
+use uuid::Uuid;
+
+use super::{UndoChange, UndoDirection, UndoOperation};
+use crate::history::{
+    self, repository::insert_events, HistoryEntityType, HistoryEvent, HistoryField,
+};
+use crate::project::{Project, Section};
+use crate::recurring_task::RecurringTask;
+use crate::task::Task;
+
+// Older operations are dropped once the history grows past this
+const MAX_UNDO_OPERATIONS: i64 = 100;
+
+#[async_trait]
+pub trait UndoRepository: Send + Sync {
+    async fn push(&mut self, operation: &UndoOperation) -> Result<(), sqlx::Error>;
+    async fn find_last_applied(&mut self) -> Result<Option<UndoOperation>, sqlx::Error>;
+    async fn find_last_undone(&mut self) -> Result<Option<UndoOperation>, sqlx::Error>;
+    async fn apply(
+        &mut self,
+        operation: &mut UndoOperation,
+        direction: UndoDirection,
+    ) -> Result<(), sqlx::Error>;
+    async fn commit(&mut self, operation: &UndoOperation) -> Result<(), sqlx::Error>;
+}
+
+pub struct SqliteUndoRepository {
+    connection: PoolConnection<Sqlite>,
+}
+
+impl SqliteUndoRepository {
+    pub fn new(connection: PoolConnection<Sqlite>) -> Self {
+        Self { connection }
+    }
+
+    fn row_to_operation(row: sqlx::sqlite::SqliteRow) -> Result<UndoOperation, sqlx::Error> {
+        let id = Uuid::parse_str(row.get("id")).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let changes = serde_json::from_str(row.get("changes"))
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let created_at_utc = DateTime::parse_from_rfc3339(row.get("created_at_utc"))
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?
+            .with_timezone(&Utc);
+        let undone_at_utc = match row.get::<Option<String>, _>("undone_at_utc") {
+            Some(date) => Some(
+                DateTime::parse_from_rfc3339(&date)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?
+                    .with_timezone(&Utc),
+            ),
+            None => None,
+        };
+
+        Ok(UndoOperation {
+            id,
+            description: row.get("description"),
+            changes,
+            created_at_utc,
+            undone_at_utc,
+        })
+    }
+}
+
+async fn write_task(
+    connection: &mut SqliteConnection,
+    task_id: Uuid,
+    task: Option<&Task>,
+) -> Result<(), sqlx::Error> {
+    let task = match task {
+        Some(task) => task,
+        None => {
+            // Labels and dependencies are left in place so writing the task back restores them
+            sqlx::query("DELETE FROM tasks WHERE id = ?1")
+                .bind(task_id.to_string())
+                .execute(&mut *connection)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO tasks (id, title, description, project_id, parent_task_id, due_at_utc, priority, created_at_utc, completed_at_utc, updated_at_utc, deleted_at_utc, archived_at_utc, section_id, position, auto_complete, estimated_minutes)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
+        ON CONFLICT(id) DO UPDATE SET
+            title = excluded.title,
+            description = excluded.description,
+            project_id = excluded.project_id,
+            parent_task_id = excluded.parent_task_id,
+            due_at_utc = excluded.due_at_utc,
+            priority = excluded.priority,
+            created_at_utc = excluded.created_at_utc,
+            completed_at_utc = excluded.completed_at_utc,
+            updated_at_utc = excluded.updated_at_utc,
+            deleted_at_utc = excluded.deleted_at_utc,
+            archived_at_utc = excluded.archived_at_utc,
+            section_id = excluded.section_id,
+            position = excluded.position,
+            auto_complete = excluded.auto_complete,
+            estimated_minutes = excluded.estimated_minutes
+        "#,
+    )
+    .bind(task.id.to_string())
+    .bind(&task.title)
+    .bind(&task.description)
+    .bind(task.project_id.map(|id| id.to_string()))
+    .bind(task.parent_task_id.map(|id| id.to_string()))
+    .bind(task.due_at_utc.map(|date| date.to_rfc3339()))
+    .bind(task.priority.level())
+    .bind(task.created_at_utc.to_rfc3339())
+    .bind(task.completed_at_utc.map(|date| date.to_rfc3339()))
+    .bind(task.updated_at_utc.to_rfc3339())
+    .bind(task.deleted_at_utc.map(|date| date.to_rfc3339()))
+    .bind(task.archived_at_utc.map(|date| date.to_rfc3339()))
+    .bind(task.section_id.map(|id| id.to_string()))
+    .bind(&task.position)
+    .bind(task.auto_complete)
+    .bind(task.estimated_minutes)
+    .execute(&mut *connection)
+    .await?;
+
+    Ok(())
+}
+
+async fn write_project(
+    connection: &mut SqliteConnection,
+    project_id: Uuid,
+    project: Option<&Project>,
+) -> Result<(), sqlx::Error> {
+    let project = match project {
+        Some(project) => project,
+        None => {
+            sqlx::query("DELETE FROM projects WHERE id = ?1")
+                .bind(project_id.to_string())
+                .execute(&mut *connection)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO projects (id, title, emoji, color, description, created_at_utc, updated_at_utc, archived_at_utc, is_favorite)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+        ON CONFLICT(id) DO UPDATE SET
+            title = excluded.title,
+            emoji = excluded.emoji,
+            color = excluded.color,
+            description = excluded.description,
+            created_at_utc = excluded.created_at_utc,
+            updated_at_utc = excluded.updated_at_utc,
+            archived_at_utc = excluded.archived_at_utc,
+            is_favorite = excluded.is_favorite
+        "#,
+    )
+    .bind(project.id.to_string())
+    .bind(&project.title)
+    .bind(&project.emoji)
+    .bind(&project.color)
+    .bind(&project.description)
+    .bind(project.created_at_utc.to_rfc3339())
+    .bind(project.updated_at_utc.to_rfc3339())
+    .bind(project.archived_at_utc.map(|date| date.to_rfc3339()))
+    .bind(project.is_favorite)
+    .execute(&mut *connection)
+    .await?;
+
+    Ok(())
+}
+
+async fn write_recurring_task(
+    connection: &mut SqliteConnection,
+    recurring_task_id: Uuid,
+    recurring_task: Option<&RecurringTask>,
+) -> Result<(), sqlx::Error> {
+    let recurring_task = match recurring_task {
+        Some(recurring_task) => recurring_task,
+        None => {
+            sqlx::query("DELETE FROM recurring_tasks WHERE id = ?1")
+                .bind(recurring_task_id.to_string())
+                .execute(&mut *connection)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO recurring_tasks (id, task_id, frequency, interval, rule, anchor, until_utc, remaining_occurrences, next_due_at_utc, created_at_utc, updated_at_utc)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+        ON CONFLICT(id) DO UPDATE SET
+            task_id = excluded.task_id,
+            frequency = excluded.frequency,
+            interval = excluded.interval,
+            rule = excluded.rule,
+            anchor = excluded.anchor,
+            until_utc = excluded.until_utc,
+            remaining_occurrences = excluded.remaining_occurrences,
+            next_due_at_utc = excluded.next_due_at_utc,
+            created_at_utc = excluded.created_at_utc,
+            updated_at_utc = excluded.updated_at_utc
+        "#,
+    )
+    .bind(recurring_task.id.to_string())
+    .bind(recurring_task.task_id.to_string())
+    .bind(&recurring_task.frequency)
+    .bind(recurring_task.interval)
+    .bind(&recurring_task.rule)
+    .bind(&recurring_task.anchor)
+    .bind(recurring_task.until_utc.map(|date| date.to_rfc3339()))
+    .bind(recurring_task.remaining_occurrences)
+    .bind(recurring_task.next_due_at_utc.to_rfc3339())
+    .bind(recurring_task.created_at_utc.to_rfc3339())
+    .bind(recurring_task.updated_at_utc.to_rfc3339())
+    .execute(&mut *connection)
+    .await?;
+
+    Ok(())
+}
+
+async fn write_section(
+    connection: &mut SqliteConnection,
+    section_id: Uuid,
+    section: Option<&Section>,
+) -> Result<(), sqlx::Error> {
+    let section = match section {
+        Some(section) => section,
+        None => {
+            sqlx::query("DELETE FROM sections WHERE id = ?1")
+                .bind(section_id.to_string())
+                .execute(&mut *connection)
+                .await?;
+            return Ok(());
+        }
+    };
+
+    sqlx::query(
+        r#"
+        INSERT INTO sections (id, project_id, title, position, created_at_utc, updated_at_utc)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        ON CONFLICT(id) DO UPDATE SET
+            project_id = excluded.project_id,
+            title = excluded.title,
+            position = excluded.position,
+            created_at_utc = excluded.created_at_utc,
+            updated_at_utc = excluded.updated_at_utc
+        "#,
+    )
+    .bind(section.id.to_string())
+    .bind(section.project_id.to_string())
+    .bind(&section.title)
+    .bind(section.position)
+    .bind(section.created_at_utc.to_rfc3339())
+    .bind(section.updated_at_utc.to_rfc3339())
+    .execute(&mut *connection)
+    .await?;
+
+    Ok(())
+}
+
+async fn insert_operation(
+    connection: &mut SqliteConnection,
+    operation: &UndoOperation,
+) -> Result<(), sqlx::Error> {
+    let changes =
+        serde_json::to_string(&operation.changes).map_err(|e| sqlx::Error::Encode(e.into()))?;
+
+    // A new operation invalidates everything that could have been redone
+    sqlx::query("DELETE FROM undo_operations WHERE undone_at_utc IS NOT NULL")
+        .execute(&mut *connection)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO undo_operations (id, description, changes, created_at_utc) VALUES (?1, ?2, ?3, ?4)",
+    )
+    .bind(operation.id.to_string())
+    .bind(&operation.description)
+    .bind(changes)
+    .bind(operation.created_at_utc.to_rfc3339())
+    .execute(&mut *connection)
+    .await?;
+
+    sqlx::query(
+        "DELETE FROM undo_operations WHERE id NOT IN (SELECT id FROM undo_operations ORDER BY created_at_utc DESC, id DESC LIMIT ?1)",
+    )
+    .bind(MAX_UNDO_OPERATIONS)
+    .execute(&mut *connection)
+    .await?;
+
+    Ok(())
+}
+
+async fn write_dependency(
+    connection: &mut SqliteConnection,
+    task_id: Uuid,
+    depends_on_task_id: Uuid,
+    exists: bool,
+) -> Result<(), sqlx::Error> {
+    let query = if exists {
+        sqlx::query(
+            "INSERT OR IGNORE INTO task_dependencies (task_id, depends_on_task_id, created_at_utc) VALUES (?1, ?2, ?3)",
+        )
+        .bind(task_id.to_string())
+        .bind(depends_on_task_id.to_string())
+        .bind(Utc::now().to_rfc3339())
+    } else {
+        sqlx::query("DELETE FROM task_dependencies WHERE task_id = ?1 AND depends_on_task_id = ?2")
+            .bind(task_id.to_string())
+            .bind(depends_on_task_id.to_string())
+    };
+    query.execute(&mut *connection).await?;
+
+    Ok(())
+}
+
+async fn write_change(
+    connection: &mut SqliteConnection,
+    change: &UndoChange,
+    direction: UndoDirection,
+) -> Result<(), sqlx::Error> {
+    match change {
+        UndoChange::Task { before, after } => {
+            let (target, other) = match direction {
+                UndoDirection::Undo => (before, after),
+                UndoDirection::Redo => (after, before),
+            };
+            let id = target.as_ref().or(other.as_ref()).map(|task| task.id);
+            if let Some(id) = id {
+                write_task(connection, id, target.as_ref()).await?;
+            }
+            if let Some(target) = target {
+                let events = history::task_changes(other.as_ref(), target);
+                insert_events(connection, &events).await?;
+            }
+        }
+        UndoChange::Project { before, after } => {
+            let (target, other) = match direction {
+                UndoDirection::Undo => (before, after),
+                UndoDirection::Redo => (after, before),
+            };
+            let id = target.as_ref().or(other.as_ref()).map(|project| project.id);
+            if let Some(id) = id {
+                write_project(connection, id, target.as_ref()).await?;
+            }
+            let events = match (target, other) {
+                (Some(target), other) => history::project_changes(other.as_ref(), target),
+                // Projects are deleted for good, unlike tasks which go to the trash
+                (None, Some(other)) => vec![HistoryEvent::new(
+                    HistoryEntityType::Project,
+                    other.id,
+                    HistoryField::DeletedAtUtc,
+                    None,
+                    Some(Utc::now().to_rfc3339()),
+                )],
+                (None, None) => Vec::new(),
+            };
+            insert_events(connection, &events).await?;
+        }
+        UndoChange::RecurringTask { before, after } => {
+            let (target, other) = match direction {
+                UndoDirection::Undo => (before, after),
+                UndoDirection::Redo => (after, before),
+            };
+            let id = target
+                .as_ref()
+                .or(other.as_ref())
+                .map(|recurring_task| recurring_task.id);
+            if let Some(id) = id {
+                write_recurring_task(connection, id, target.as_ref()).await?;
+            }
+        }
+        UndoChange::Section { before, after } => {
+            let (target, other) = match direction {
+                UndoDirection::Undo => (before, after),
+                UndoDirection::Redo => (after, before),
+            };
+            let id = target.as_ref().or(other.as_ref()).map(|section| section.id);
+            if let Some(id) = id {
+                write_section(connection, id, target.as_ref()).await?;
+            }
+        }
+        UndoChange::Dependency {
+            task_id,
+            depends_on_task_id,
+            before,
+            after,
+        } => {
+            let target = match direction {
+                UndoDirection::Undo => before,
+                UndoDirection::Redo => after,
+            };
+            write_dependency(connection, *task_id, *depends_on_task_id, *target).await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl UndoRepository for SqliteUndoRepository {
+    async fn push(&mut self, operation: &UndoOperation) -> Result<(), sqlx::Error> {
+        insert_operation(&mut self.connection, operation).await
+    }
+
+    async fn find_last_applied(&mut self) -> Result<Option<UndoOperation>, sqlx::Error> {
+        let row = sqlx::query(
+            "SELECT * FROM undo_operations WHERE undone_at_utc IS NULL ORDER BY created_at_utc DESC, id DESC LIMIT 1",
+        )
+        .fetch_optional(&mut *self.connection)
+        .await?;
+
+        row.map(Self::row_to_operation).transpose()
+    }
+
+    async fn find_last_undone(&mut self) -> Result<Option<UndoOperation>, sqlx::Error> {
+        let row = sqlx::query(
+            "SELECT * FROM undo_operations WHERE undone_at_utc IS NOT NULL ORDER BY undone_at_utc DESC, created_at_utc ASC LIMIT 1",
+        )
+        .fetch_optional(&mut *self.connection)
+        .await?;
+
+        row.map(Self::row_to_operation).transpose()
+    }
+
+    async fn apply(
+        &mut self,
+        operation: &mut UndoOperation,
+        direction: UndoDirection,
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+
+        // Undo walks the changes backwards so later changes are reverted first
+        match direction {
+            UndoDirection::Undo => {
+                for change in operation.changes.iter().rev() {
+                    write_change(&mut transaction, change, direction).await?;
+                }
+                operation.undone_at_utc = Some(Utc::now());
+            }
+            UndoDirection::Redo => {
+                for change in operation.changes.iter() {
+                    write_change(&mut transaction, change, direction).await?;
+                }
+                operation.undone_at_utc = None;
+            }
+        }
+
+        sqlx::query("UPDATE undo_operations SET undone_at_utc = ?1 WHERE id = ?2")
+            .bind(operation.undone_at_utc.map(|date| date.to_rfc3339()))
+            .bind(operation.id.to_string())
+            .execute(&mut *transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    // Writes the state after every change and records the operation, all or nothing
+    async fn commit(&mut self, operation: &UndoOperation) -> Result<(), sqlx::Error> {
+        let mut transaction = self.connection.begin().await?;
+
+        for change in operation.changes.iter() {
+            write_change(&mut transaction, change, UndoDirection::Redo).await?;
+        }
+        insert_operation(&mut transaction, operation).await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use tauri::State;
+
+use crate::errors::handle_error;
+use crate::repository::RepositoryProvider;
+use crate::undo::manager::UndoManager;
+
+#[tauri::command]
+pub async fn undo_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running undo command");
+
+    let undo_manager = UndoManager::new(&repository_provider);
+
+    let operation = undo_manager.undo().await.map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&operation).unwrap())
+}
+
+#[tauri::command]
+pub async fn redo_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running redo command");
+
+    let undo_manager = UndoManager::new(&repository_provider);
+
+    let operation = undo_manager.redo().await.map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&operation).unwrap())
+}
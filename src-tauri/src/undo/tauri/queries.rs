@@ -0,0 +1,21 @@
+use tauri::State;
+
+use crate::errors::handle_error;
+use crate::repository::RepositoryProvider;
+use crate::undo::manager::UndoManager;
+
+#[tauri::command]
+pub async fn load_undo_state_command(
+    repository_provider: State<'_, RepositoryProvider>,
+) -> Result<String, String> {
+    log::debug!("Running load undo state command");
+
+    let undo_manager = UndoManager::new(&repository_provider);
+
+    let state = undo_manager
+        .load_state()
+        .await
+        .map_err(|e| handle_error(&*e))?;
+
+    Ok(serde_json::to_string(&state).unwrap())
+}
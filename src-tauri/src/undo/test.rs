@@ -0,0 +1,259 @@
+#[cfg(test)]
+mod manager_test {
+    use crate::label::manager::LabelManager;
+    use crate::project::manager::ProjectsManager;
+    use crate::recurring_task::manager::RecurringTaskManager;
+    use crate::recurring_task::repository::RecurringTaskRepository;
+    use crate::recurring_task::Frequency;
+    use crate::repository::RepositoryProvider;
+    use crate::task::manager::TaskManager;
+    use crate::task::repository::TaskRepository;
+    use crate::task::CreateTaskData;
+    use crate::undo::manager::UndoManager;
+
+    use sqlx::migrate::MigrateDatabase;
+    use sqlx::sqlite::SqlitePool;
+    use sqlx::Sqlite;
+
+    async fn setup_test_db() -> Result<RepositoryProvider, sqlx::Error> {
+        let url = format!("sqlite://{}", ":memory:");
+
+        if !Sqlite::database_exists(&url).await.unwrap_or(false) {
+            Sqlite::create_database(&url).await?;
+        }
+
+        let pool = SqlitePool::connect(&url).await?;
+
+        // Run migrations
+        sqlx::migrate!("./migrations").run(&pool).await?;
+
+        Ok(RepositoryProvider::new(pool))
+    }
+
+    fn task_data(title: &str) -> CreateTaskData {
+        CreateTaskData {
+            title: title.to_string(),
+            description: None,
+            project_id: None,
+            due_at_utc: None,
+            priority: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_undoes_and_redoes_a_task_completion() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let undo_manager = UndoManager::new(&provider);
+
+        let task = task_manager
+            .create_task(task_data("Pay rent"))
+            .await
+            .unwrap();
+        task_manager.complete_task(task.id).await.unwrap();
+
+        let operation = undo_manager.undo().await.unwrap().unwrap();
+        assert_eq!("Complete task", operation.description);
+
+        let task_after_undo = task_manager.load_task(task.id).await.unwrap();
+        assert!(task_after_undo.completed_at_utc.is_none());
+
+        let operation = undo_manager.redo().await.unwrap().unwrap();
+        assert_eq!("Complete task", operation.description);
+
+        let task_after_redo = task_manager.load_task(task.id).await.unwrap();
+        assert!(task_after_redo.completed_at_utc.is_some());
+    }
+
+    #[tokio::test]
+    async fn undoing_a_creation_removes_the_task() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let undo_manager = UndoManager::new(&provider);
+
+        let task = task_manager
+            .create_task(task_data("Buy milk"))
+            .await
+            .unwrap();
+
+        undo_manager.undo().await.unwrap();
+        assert!(task_manager.load_by_id(task.id).await.unwrap().is_none());
+
+        // Nothing left to undo once the creation has been reverted
+        assert!(undo_manager.undo().await.unwrap().is_none());
+
+        undo_manager.redo().await.unwrap();
+        assert!(task_manager.load_by_id(task.id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn undoing_a_deletion_brings_back_the_subtasks() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let undo_manager = UndoManager::new(&provider);
+
+        let task = task_manager.create_task(task_data("Parent")).await.unwrap();
+        let task_id = task.id;
+        task_manager
+            .create_subtask_for_task(task, task_data("Child"))
+            .await
+            .unwrap();
+
+        task_manager.delete_task(task_id).await.unwrap();
+        assert!(task_manager.load_tasks(true).await.unwrap().is_empty());
+
+        undo_manager.undo().await.unwrap();
+
+        assert_eq!(2, task_manager.load_tasks(true).await.unwrap().len());
+        assert!(task_manager.load_trash().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_undoes_dependency_changes_and_keeps_labels_and_dependencies_of_removed_tasks() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let undo_manager = UndoManager::new(&provider);
+
+        let blocker = task_manager
+            .create_task(task_data("Order boxes"))
+            .await
+            .unwrap();
+        let task = task_manager
+            .create_task(task_data("Pack kitchen"))
+            .await
+            .unwrap();
+        task_manager
+            .add_dependency(task.id, blocker.id)
+            .await
+            .unwrap();
+
+        let mut label_repository = provider.label_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut label_manager = LabelManager::new(&mut label_repository, &mut task_repository);
+        let label = label_manager
+            .create_label("moving".to_string(), None)
+            .await
+            .unwrap();
+        label_manager
+            .attach_label_to_task(label.id, task.id)
+            .await
+            .unwrap();
+
+        let operation = undo_manager.undo().await.unwrap().unwrap();
+        assert_eq!("Add dependency", operation.description);
+        assert!(!task_manager.load_task(task.id).await.unwrap().blocked);
+
+        // Removing the task on undo and bringing it back on redo keeps what was attached to it
+        undo_manager.undo().await.unwrap();
+        assert!(task_manager.load_by_id(task.id).await.unwrap().is_none());
+        undo_manager.redo().await.unwrap();
+        let labels = label_manager.load_labels_for_task(task.id).await.unwrap();
+        assert_eq!(1, labels.len());
+
+        undo_manager.redo().await.unwrap();
+        assert!(task_manager.load_task(task.id).await.unwrap().blocked);
+
+        task_manager
+            .remove_dependency(task.id, blocker.id)
+            .await
+            .unwrap();
+        assert!(!task_manager.load_task(task.id).await.unwrap().blocked);
+        let operation = undo_manager.undo().await.unwrap().unwrap();
+        assert_eq!("Remove dependency", operation.description);
+        assert!(task_manager.load_task(task.id).await.unwrap().blocked);
+    }
+
+    #[tokio::test]
+    async fn a_new_mutation_clears_the_redo_stack() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let undo_manager = UndoManager::new(&provider);
+
+        task_manager.create_task(task_data("First")).await.unwrap();
+        undo_manager.undo().await.unwrap();
+
+        let state = undo_manager.load_state().await.unwrap();
+        assert!(state.undo.is_none());
+        assert_eq!(Some("Create task".to_string()), state.redo);
+
+        task_manager.create_task(task_data("Second")).await.unwrap();
+
+        let state = undo_manager.load_state().await.unwrap();
+        assert_eq!(Some("Create task".to_string()), state.undo);
+        assert!(state.redo.is_none());
+        assert!(undo_manager.redo().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn it_undoes_a_project_update() {
+        let provider = setup_test_db().await.unwrap();
+        let mut project_repository = provider.project_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut projects_manager = ProjectsManager::new(
+            &mut project_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+
+        let project = projects_manager
+            .create_project("Garden".to_string(), None, None, None)
+            .await
+            .unwrap();
+        projects_manager
+            .update_project(project.id, "Backyard".to_string(), None, None, None)
+            .await
+            .unwrap();
+        projects_manager.add_favorite(project.id).await.unwrap();
+
+        let undo_manager = UndoManager::new(&provider);
+        undo_manager.undo().await.unwrap();
+        undo_manager.undo().await.unwrap();
+
+        let projects = projects_manager.load_all(true).await.unwrap();
+        assert_eq!(1, projects.len());
+        assert_eq!("Garden", projects[0].title);
+        assert!(!projects[0].is_favorite);
+    }
+
+    #[tokio::test]
+    async fn undoing_a_recurring_completion_removes_the_next_occurrence() {
+        let provider = setup_test_db().await.unwrap();
+        let task_manager = TaskManager::new(&provider);
+        let undo_manager = UndoManager::new(&provider);
+
+        let task = task_manager
+            .create_task(task_data("Water plants"))
+            .await
+            .unwrap();
+
+        let mut recurring_task_repository = provider.recurring_task_repository().await.unwrap();
+        let mut task_repository = provider.task_repository().await.unwrap();
+        let mut undo_repository = provider.undo_repository().await.unwrap();
+        let mut recurring_task_manager = RecurringTaskManager::new(
+            &mut recurring_task_repository,
+            &mut task_repository,
+            &mut undo_repository,
+        );
+        recurring_task_manager
+            .setup_recurring_task(task.id, Frequency::Weekly, 1)
+            .await
+            .unwrap();
+
+        task_manager.complete_task(task.id).await.unwrap();
+        assert_eq!(1, task_manager.load_tasks(false).await.unwrap().len());
+
+        undo_manager.undo().await.unwrap();
+
+        let open_tasks = task_manager.load_tasks(false).await.unwrap();
+        assert_eq!(1, open_tasks.len());
+        assert_eq!(task.id, open_tasks[0].id);
+
+        let recurring_task = recurring_task_repository
+            .find_by_task_id(task.id)
+            .await
+            .unwrap();
+        assert!(recurring_task.is_some());
+        assert!(task_repository.find_by_id(task.id).await.unwrap().is_some());
+    }
+}
@@ -30,6 +30,10 @@ declare module "@tanstack/react-router" {
     }
 }
 
+type Priority = "P1" | "P2" | "P3" | "P4"
+
+export type { Priority }
+
 type Task = {
     id: string
     title: string
@@ -37,13 +41,33 @@ type Task = {
     project_id: string | null
     parent_task_id: string | null
     due_at_utc: string | null
+    priority: Priority
     created_at_utc: string
     updated_at_utc: string
     completed_at_utc: string | null
+    deleted_at_utc: string | null
+    archived_at_utc: string | null
+    section_id: string | null
+    position: string
+    auto_complete: boolean | null
+    estimated_minutes: number | null
+    blocked: boolean
+    subtask_total: number
+    subtask_completed: number
 }
 
 export type { Task }
 
+type TaskTreeNode = {
+    task: Task
+    depth: number
+    descendant_count: number
+    completed_descendant_count: number
+    children: Array<TaskTreeNode>
+}
+
+export type { TaskTreeNode }
+
 type Project = {
     id: string
     title: string
@@ -55,6 +79,105 @@ type Project = {
 
 export type { Project }
 
+type Section = {
+    id: string
+    project_id: string
+    title: string
+    position: number
+    created_at_utc: string
+    updated_at_utc: string
+}
+
+type ProjectDetail = {
+    project: Project
+    sections: Array<{ section: Section, tasks: Array<Task> }>
+    unsectioned_tasks: Array<Task>
+}
+
+export type { Section, ProjectDetail }
+
+type TemplateTask = {
+    key: string
+    parent_key: string | null
+    section_key: string | null
+    title: string
+    description: string | null
+    priority: Priority
+    position: string
+    auto_complete: boolean | null
+    estimated_minutes: number | null
+    due_offset_seconds: number | null
+}
+
+type ProjectTemplate = {
+    id: string
+    title: string
+    emoji: string | null
+    color: string | null
+    description: string | null
+    sections: Array<{ key: string, title: string, position: number }>
+    tasks: Array<TemplateTask>
+    created_at_utc: string
+    updated_at_utc: string
+}
+
+export type { TemplateTask, ProjectTemplate }
+
+type TimeEntry = {
+    id: string
+    task_id: string
+    started_at_utc: string
+    ended_at_utc: string | null
+    created_at_utc: string
+    updated_at_utc: string
+}
+
+type TaskTimeStats = {
+    task_id: string
+    task_title: string
+    project_id: string | null
+    estimated_minutes: number | null
+    tracked_seconds: number
+}
+
+type ProjectTimeStats = {
+    project_id: string
+    project_title: string
+    tracked_seconds: number
+}
+
+export type { TimeEntry, TaskTimeStats, ProjectTimeStats }
+
+type PomodoroPhase = "idle" | "work" | "short_break" | "long_break"
+
+type PomodoroState = {
+    phase: PomodoroPhase
+    task_id: string | null
+    phase_started_at_utc: string | null
+    phase_ends_at_utc: string | null
+    completed_work_sessions: number
+}
+
+type PomodoroSession = {
+    id: string
+    task_id: string | null
+    started_at_utc: string
+    ended_at_utc: string
+    created_at_utc: string
+}
+
+export type { PomodoroPhase, PomodoroState, PomodoroSession }
+
+type Label = {
+    id: string
+    name: string
+    color: string | null
+    created_at_utc: string
+    updated_at_utc: string
+}
+
+export type { Label }
+
 export enum Frequency {
     Daily = "daily",
     Weekly = "weekly",
@@ -62,14 +185,83 @@ export enum Frequency {
     Yearly = "yearly"
 }
 
+export enum RecurrenceAnchor {
+    Scheduled = "scheduled",
+    Completion = "completion"
+}
+
 type RecurringTask = {
     id: string
     task_id: string
     frequency: Frequency
     interval: number
+    rule: string | null
+    anchor: RecurrenceAnchor
+    until_utc: string | null
+    remaining_occurrences: number | null
     next_due_at_utc: string
     created_at_utc: string
     updated_at_utc: string
 }
 
-export type { RecurringTask }
\ No newline at end of file
+export type { RecurringTask }
+type SearchHit = {
+    entity_type: "task" | "project"
+    id: string
+    title: string
+    snippet: string
+    project_id: string | null
+    rank: number
+}
+
+export type { SearchHit }
+
+type TaskSortOrder = "priority" | "due_date" | "created_at" | "updated_at" | "title" | "position"
+
+type SavedView = {
+    id: string
+    name: string
+    icon: string | null
+    filter: string
+    sort_order: TaskSortOrder
+    created_at_utc: string
+    updated_at_utc: string
+}
+
+export type { SavedView, TaskSortOrder }
+
+type UndoState = {
+    undo: string | null
+    redo: string | null
+}
+
+export type { UndoState }
+
+type HistoryField =
+    | "created"
+    | "title"
+    | "description"
+    | "project_id"
+    | "parent_task_id"
+    | "due_at_utc"
+    | "priority"
+    | "completed_at_utc"
+    | "deleted_at_utc"
+    | "emoji"
+    | "color"
+    | "archived_at_utc"
+    | "is_favorite"
+    | "section_id"
+    | "auto_complete"
+
+type HistoryEvent = {
+    id: string
+    entity_type: "task" | "project"
+    entity_id: string
+    field: HistoryField
+    old_value: string | null
+    new_value: string | null
+    created_at_utc: string
+}
+
+export type { HistoryEvent, HistoryField }